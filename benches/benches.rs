@@ -1,4 +1,8 @@
-use atoi::{FromRadix10, FromRadix10Checked, FromRadix10Signed, FromRadix16, FromRadix16Checked};
+use atoi::{
+    FromRadix10, FromRadix10Checked, FromRadix10Signed, FromRadix16, FromRadix16Checked,
+    FromRadix16Signed, FromRadix16SignedChecked, ToRadix10, from_fixed_width_radix_10,
+    parse_exact_radix_10,
+};
 use criterion::{Criterion, criterion_group, criterion_main};
 use std::{hint::black_box, str};
 
@@ -50,6 +54,18 @@ pub fn u32_four_digit_hex_number_checked(c: &mut Criterion) {
     });
 }
 
+pub fn i32_negative_four_digit_hex_number(c: &mut Criterion) {
+    c.bench_function("negative i32 four digit hex number", |b| {
+        b.iter(|| i32::from_radix_16_signed(black_box(b"-1996")))
+    });
+}
+
+pub fn i32_negative_four_digit_hex_number_checked(c: &mut Criterion) {
+    c.bench_function("negative i32 checked four digit hex number", |b| {
+        b.iter(|| i32::from_radix_16_signed_checked(black_box(b"-1996")))
+    });
+}
+
 pub fn i32_negative_four_digit_number(c: &mut Criterion) {
     c.bench_function("negative i32 four digit number", |b| {
         b.iter(|| i32::from_radix_10_signed(black_box(b"-1996")))
@@ -74,6 +90,25 @@ pub fn i128_signed_four_digit_number(c: &mut Criterion) {
     });
 }
 
+pub fn i32_format_four_digit_number(c: &mut Criterion) {
+    c.bench_function("format i32 four digit number", |b| {
+        let mut buffer = [0; 11];
+        b.iter(|| black_box(1996i32).to_radix_10(&mut buffer))
+    });
+}
+
+pub fn u64_fixed_width_sixteen_digit_number(c: &mut Criterion) {
+    c.bench_function("u64 fixed width sixteen digit number", |b| {
+        b.iter(|| from_fixed_width_radix_10::<u64, 16>(black_box(b"0000001234567890")))
+    });
+}
+
+pub fn u64_exact_sixteen_digit_number(c: &mut Criterion) {
+    c.bench_function("u64 exact sixteen digit number", |b| {
+        b.iter(|| parse_exact_radix_10::<u64>(black_box(b"0000001234567890")))
+    });
+}
+
 pub fn u32_through_utf8(c: &mut Criterion) {
     c.bench_function("u32 via UTF-8", |b| {
         b.iter(|| {
@@ -103,10 +138,15 @@ criterion_group!(
     i32_four_digit_hex_number_checked,
     u32_four_digit_hex_number,
     u32_four_digit_hex_number_checked,
+    i32_negative_four_digit_hex_number,
+    i32_negative_four_digit_hex_number_checked,
     i32_signed_four_digit_number,
     i32_negative_four_digit_number,
     i32_positive_four_digit_number,
     i128_signed_four_digit_number,
+    i32_format_four_digit_number,
+    u64_fixed_width_sixteen_digit_number,
+    u64_exact_sixteen_digit_number,
     u32_through_utf8,
     i128_through_utf8,
 );
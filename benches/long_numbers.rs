@@ -0,0 +1,52 @@
+use atoi::{FromRadix10, FromRadix10Checked, FromRadix10SignedChecked};
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::{hint::black_box, str};
+
+const DIGITS: &[u8; 39] = b"123456789012345678901234567890123456789";
+
+pub fn u128_by_num_digits(c: &mut Criterion) {
+    let mut group = c.benchmark_group("u128 by number of digits");
+    for num_digits in 1..=DIGITS.len() {
+        let text = &DIGITS[..num_digits];
+        group.bench_with_input(
+            BenchmarkId::new("unchecked", num_digits),
+            text,
+            |b, text| b.iter(|| u128::from_radix_10(black_box(text))),
+        );
+        group.bench_with_input(BenchmarkId::new("checked", num_digits), text, |b, text| {
+            b.iter(|| u128::from_radix_10_checked(black_box(text)))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("via UTF-8", num_digits),
+            text,
+            |b, text| b.iter(|| str::from_utf8(black_box(text)).unwrap().parse::<u128>()),
+        );
+    }
+    group.finish();
+}
+
+pub fn i64_by_num_digits(c: &mut Criterion) {
+    let mut group = c.benchmark_group("i64 by number of digits");
+    for num_digits in 1..=18 {
+        let text = &DIGITS[..num_digits];
+        group.bench_with_input(
+            BenchmarkId::new("signed checked", num_digits),
+            text,
+            |b, text| b.iter(|| i64::from_radix_10_signed_checked(black_box(text))),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("via UTF-8", num_digits),
+            text,
+            |b, text| b.iter(|| str::from_utf8(black_box(text)).unwrap().parse::<i64>()),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    name = long_numbers;
+    config = Criterion::default().significance_level(0.01);
+    targets = u128_by_num_digits,
+    i64_by_num_digits,
+);
+criterion_main!(long_numbers);
@@ -0,0 +1,348 @@
+//! Parsing numbers incrementally, whose digits may be spread over several chunks of input.
+
+use crate::{
+    FromRadix10Checked, FromRadix10SignedChecked, FromRadix16Checked, FromRadix16SignedChecked,
+    ParseIntegerError, Sign,
+};
+
+/// Accumulates the digits of a number in a positional numeral system with radix `RADIX`, which
+/// are fed to it in chunks. Useful if the digits of a number may straddle the boundaries of
+/// buffers, e.g. when reading from a socket. Accumulators exist for radix 10
+/// ([`DecimalAccumulator`]) and radix 16 ([`HexAccumulator`]).
+///
+/// The number is kept up to date with every chunk, so an overflow is reported by
+/// [`Accumulator::feed`] right away, rather than only once the number is finished.
+///
+/// # Example
+///
+/// ```
+/// use atoi::DecimalAccumulator;
+///
+/// let mut accumulator = DecimalAccumulator::<u32>::new();
+/// // All bytes belong to the number, so it may continue in the next chunk
+/// assert_eq!(Ok(None), accumulator.feed(b"12"));
+/// // The number ends in front of the space
+/// assert_eq!(Ok(Some(2)), accumulator.feed(b"34 56"));
+/// assert_eq!(4, accumulator.num_digits());
+/// assert_eq!(Ok(1234), accumulator.finish());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Accumulator<I, const RADIX: u32> {
+    /// Parses the sign and the significant digits.
+    parse: fn(&[u8]) -> (Option<I>, usize),
+    /// Whether the number may start with a sign.
+    signed: bool,
+    sign: Option<Sign>,
+    /// A slot for the sign, followed by the significant digits, i.e. all digits but leading zeros.
+    digits: [u8; 1 + MAX_DIGITS],
+    num_significant_digits: usize,
+    num_digits: usize,
+    /// The number parsed from the digits fed so far. `None` if no digit has been fed yet, or the
+    /// digits are not representable by `I`.
+    number: Option<I>,
+    /// `true` once a byte not belonging to the number has been encountered.
+    complete: bool,
+}
+
+/// Accumulates the digits of a decimal number. See [`Accumulator`].
+pub type DecimalAccumulator<I> = Accumulator<I, 10>;
+
+/// Accumulates the digits of a hexadecimal number. See [`Accumulator`].
+pub type HexAccumulator<I> = Accumulator<I, 16>;
+
+/// Maximum number of significant digits the accumulator keeps. Enough for any integer of up to 128
+/// bits in any radix. Numbers with more significant digits are reported as overflow.
+const MAX_DIGITS: usize = 128;
+
+impl<I> Accumulator<I, 10>
+where
+    I: FromRadix10Checked,
+{
+    /// An accumulator for a decimal number without a sign. Acts like [`FromRadix10Checked`].
+    pub fn new() -> Self {
+        Self::with_parse(false, I::from_radix_10_checked)
+    }
+}
+
+impl<I> Accumulator<I, 10>
+where
+    I: FromRadix10SignedChecked,
+{
+    /// An accumulator for a decimal number with an optional leading sign. Acts like
+    /// [`FromRadix10SignedChecked`].
+    pub fn signed() -> Self {
+        Self::with_parse(true, I::from_radix_10_signed_checked)
+    }
+}
+
+impl<I> Accumulator<I, 16>
+where
+    I: FromRadix16Checked,
+{
+    /// An accumulator for a hexadecimal number without a sign. Acts like [`FromRadix16Checked`].
+    pub fn new() -> Self {
+        Self::with_parse(false, I::from_radix_16_checked)
+    }
+}
+
+impl<I> Accumulator<I, 16>
+where
+    I: FromRadix16SignedChecked,
+{
+    /// An accumulator for a hexadecimal number with an optional leading sign. Acts like
+    /// [`FromRadix16SignedChecked`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::HexAccumulator;
+    ///
+    /// let mut accumulator = HexAccumulator::<i8>::signed();
+    /// assert_eq!(Ok(None), accumulator.feed(b"-"));
+    /// assert_eq!(Ok(None), accumulator.feed(b"8"));
+    /// assert_eq!(Ok(Some(1)), accumulator.feed(b"0\n"));
+    /// assert_eq!(Ok(-0x80), accumulator.finish());
+    /// ```
+    pub fn signed() -> Self {
+        Self::with_parse(true, I::from_radix_16_signed_checked)
+    }
+}
+
+impl<I, const RADIX: u32> Accumulator<I, RADIX> {
+    fn with_parse(signed: bool, parse: fn(&[u8]) -> (Option<I>, usize)) -> Self {
+        Accumulator {
+            parse,
+            signed,
+            sign: None,
+            digits: [b'0'; 1 + MAX_DIGITS],
+            num_significant_digits: 0,
+            num_digits: 0,
+            number: None,
+            complete: false,
+        }
+    }
+
+    /// Feeds the next chunk of input to the accumulator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::{DecimalAccumulator, ParseIntegerError};
+    ///
+    /// let mut accumulator = DecimalAccumulator::<u8>::new();
+    /// assert_eq!(Ok(None), accumulator.feed(b"25"));
+    /// // The number overflows, even though it may still continue
+    /// assert_eq!(Err(ParseIntegerError::PosOverflow), accumulator.feed(b"6"));
+    /// assert_eq!(Ok(Some(1)), accumulator.feed(b"0 "));
+    /// assert_eq!(Err(ParseIntegerError::PosOverflow), accumulator.finish());
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// `Ok(None)` if all of `chunk` belongs to the number, so more digits may follow in the next
+    /// chunk. `Err` if this is the case, but the digits fed so far already overflow `I`. The error
+    /// is [`ParseIntegerError::PosOverflow`] or [`ParseIntegerError::NegOverflow`]. Otherwise the
+    /// number is complete and the index of the first byte not belonging to it is returned. Once
+    /// the number is complete, any further chunk is ignored and `Ok(Some(0))` is returned.
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<Option<usize>, ParseIntegerError> {
+        if self.complete {
+            return Ok(Some(0));
+        }
+        let mut index = 0;
+        if self.signed
+            && self.sign.is_none()
+            && self.num_digits == 0
+            && let Some(sign) = chunk.first().and_then(|&byte| Sign::try_from(byte))
+        {
+            self.sign = Some(sign);
+            self.digits[0] = chunk[0];
+            index = 1;
+        }
+        let num_digits = self.num_digits;
+        let overflow = self.is_overflow();
+        while index != chunk.len() && (chunk[index] as char).is_digit(RADIX) {
+            // Digits beyond the capacity of the buffer can only mean an overflow, which has
+            // already been detected. We count them nonetheless.
+            if chunk[index] != b'0' || self.num_significant_digits != 0 {
+                if let Some(slot) = self.digits.get_mut(1 + self.num_significant_digits) {
+                    *slot = chunk[index];
+                }
+                self.num_significant_digits += 1;
+            }
+            self.num_digits += 1;
+            index += 1;
+        }
+        if self.num_digits != num_digits && !overflow {
+            self.number = self.parse_digits();
+        }
+        if index != chunk.len() {
+            self.complete = true;
+            Ok(Some(index))
+        } else if self.is_overflow() {
+            Err(self.overflow_error())
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Number of digits fed to the accumulator so far. Leading zeros are included.
+    pub fn num_digits(&self) -> usize {
+        self.num_digits
+    }
+
+    /// `true` once a byte not belonging to the number has been fed to the accumulator.
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// Finishes parsing, e.g. because the number is complete, or the end of the input has been
+    /// reached. Errors are reported like by [`crate::try_atoi`], with positions relative to the
+    /// start of the number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::{DecimalAccumulator, ParseIntegerError};
+    ///
+    /// let accumulator = DecimalAccumulator::<u32>::new();
+    /// assert_eq!(Err(ParseIntegerError::Empty), accumulator.finish());
+    ///
+    /// let mut accumulator = DecimalAccumulator::<u32>::new();
+    /// accumulator.feed(b"x").unwrap();
+    /// assert_eq!(Err(ParseIntegerError::InvalidDigit { position: 0 }), accumulator.finish());
+    /// ```
+    pub fn finish(self) -> Result<I, ParseIntegerError> {
+        if self.num_digits == 0 {
+            return Err(match (self.sign, self.complete) {
+                (None, false) => ParseIntegerError::Empty,
+                (Some(_), false) => ParseIntegerError::SignOnly,
+                (sign, true) => ParseIntegerError::InvalidDigit {
+                    position: usize::from(sign.is_some()),
+                },
+            });
+        }
+        match self.number {
+            Some(number) => Ok(number),
+            // Zero is always in range, unless the integer type can not represent it.
+            None if self.num_significant_digits == 0 => Err(ParseIntegerError::Zero),
+            None => Err(self.overflow_error()),
+        }
+    }
+
+    /// Parses the sign and the significant digits fed so far.
+    fn parse_digits(&self) -> Option<I> {
+        if self.num_significant_digits > MAX_DIGITS {
+            return None;
+        }
+        // If all digits have been zeros, we parse the single zero following the sign slot.
+        let end = 1 + self.num_significant_digits.max(1);
+        let start = usize::from(self.sign.is_none());
+        (self.parse)(&self.digits[start..end]).0
+    }
+
+    /// `true` if the digits fed so far are not representable by `I`. More digits can only make
+    /// the number larger, so this never changes back.
+    fn is_overflow(&self) -> bool {
+        self.num_significant_digits != 0 && self.number.is_none()
+    }
+
+    fn overflow_error(&self) -> ParseIntegerError {
+        match self.sign {
+            Some(Sign::Minus) => ParseIntegerError::NegOverflow,
+            _ => ParseIntegerError::PosOverflow,
+        }
+    }
+}
+
+impl<I> Default for Accumulator<I, 10>
+where
+    I: FromRadix10Checked,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I> Default for Accumulator<I, 16>
+where
+    I: FromRadix16Checked,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Integer;
+
+    #[test]
+    fn accumulate_digits_across_chunks() {
+        let mut accumulator = DecimalAccumulator::<u64>::new();
+        for chunk in [&b"1844674407"[..], b"", b"3709551615"] {
+            assert_eq!(Ok(None), accumulator.feed(chunk));
+        }
+        assert_eq!(Ok(u64::MAX), accumulator.finish());
+
+        let mut accumulator = DecimalAccumulator::<u64>::new();
+        assert_eq!(
+            Err(ParseIntegerError::PosOverflow),
+            accumulator.feed(b"18446744073709551616")
+        );
+        assert_eq!(Ok(Some(0)), accumulator.feed(b","));
+        assert!(accumulator.is_complete());
+        assert_eq!(Ok(Some(0)), accumulator.feed(b"1"));
+        assert_eq!(Err(ParseIntegerError::PosOverflow), accumulator.finish());
+
+        let mut accumulator = DecimalAccumulator::<i8>::signed();
+        assert_eq!(Ok(None), accumulator.feed(b"-12"));
+        assert_eq!(Err(ParseIntegerError::NegOverflow), accumulator.feed(b"9"));
+        assert_eq!(Err(ParseIntegerError::NegOverflow), accumulator.feed(b""));
+        assert_eq!(3, accumulator.num_digits());
+        assert_eq!(Err(ParseIntegerError::NegOverflow), accumulator.finish());
+
+        // A sign is only allowed in front of the first digit
+        let mut accumulator = DecimalAccumulator::<i8>::signed();
+        assert_eq!(Ok(None), accumulator.feed(b"1"));
+        assert_eq!(Ok(Some(0)), accumulator.feed(b"-1"));
+        assert_eq!(Ok(1), accumulator.finish());
+
+        let mut accumulator = DecimalAccumulator::<i8>::signed();
+        assert_eq!(Ok(None), accumulator.feed(b"+"));
+        assert_eq!(Err(ParseIntegerError::SignOnly), accumulator.finish());
+
+        let mut accumulator = HexAccumulator::<i8>::signed();
+        assert_eq!(Ok(Some(1)), accumulator.feed(b"+x"));
+        assert_eq!(
+            Err(ParseIntegerError::InvalidDigit { position: 1 }),
+            accumulator.finish()
+        );
+
+        // Signs are not allowed for unsigned accumulators
+        let mut accumulator = HexAccumulator::<i8>::new();
+        assert_eq!(Ok(Some(0)), accumulator.feed(b"-1"));
+        assert_eq!(
+            Err(ParseIntegerError::InvalidDigit { position: 0 }),
+            accumulator.finish()
+        );
+
+        // Leading zeros do not count towards the digits of a number
+        let mut accumulator = DecimalAccumulator::<i8>::signed();
+        assert_eq!(Ok(None), accumulator.feed(b"-"));
+        for _ in 0..100 {
+            assert_eq!(Ok(None), accumulator.feed(b"00"));
+        }
+        assert_eq!(Ok(Some(3)), accumulator.feed(b"128 "));
+        assert_eq!(Ok(i8::MIN), accumulator.finish());
+
+        let mut accumulator = DecimalAccumulator::<core::num::NonZeroU8>::new();
+        assert_eq!(Ok(Some(2)), accumulator.feed(b"00\n"));
+        assert_eq!(Err(ParseIntegerError::Zero), accumulator.finish());
+
+        let mut accumulator = HexAccumulator::<Integer<i16>>::signed();
+        assert_eq!(Ok(None), accumulator.feed(b"-80"));
+        assert_eq!(Ok(Some(2)), accumulator.feed(b"00,"));
+        assert_eq!(Ok(i16::MIN), accumulator.finish().map(|n| n.0));
+    }
+}
@@ -0,0 +1,206 @@
+//! Parsing integers directly from the buffer of an asynchronous reader.
+
+/// Implements an extension trait for the `AsyncBufRead` trait of an async runtime, together with
+/// the futures returned by its methods, in a module named `$module`.
+macro_rules! impl_async_buf_read_ext {
+    ($module:ident, $async_buf_read:path, $doc:literal) => {
+        #[doc = $doc]
+        pub mod $module {
+            use core::{
+                future::Future,
+                pin::Pin,
+                task::{Context, Poll},
+            };
+            use std::io;
+
+            use crate::{
+                FromRadix10SignedChecked,
+                io::{BufferedParser, IntParser, IntsUntilParser},
+            };
+
+            use $async_buf_read as AsyncBufRead;
+
+            /// Extends `AsyncBufRead` with methods parsing decimal integers directly from its
+            /// internal buffer. Mirrors [`crate::BufReadIntegerExt`].
+            pub trait AsyncBufReadIntegerExt: AsyncBufRead {
+                /// Skips leading whitespace and parses a decimal integer with an optional sign. The
+                /// first byte after the number is not consumed. See
+                /// [`crate::BufReadIntegerExt::read_int`].
+                fn read_int<I>(&mut self) -> ReadInt<'_, Self, I>
+                where
+                    Self: Unpin,
+                    I: FromRadix10SignedChecked,
+                {
+                    ReadInt {
+                        reader: self,
+                        parser: IntParser::new(),
+                    }
+                }
+
+                /// Parses decimal integers separated by whitespace, until `delimiter` or the end of
+                /// the stream is reached. See
+                /// [`crate::BufReadIntegerExt::read_ints_until`].
+                fn read_ints_until<I, F>(
+                    &mut self,
+                    delimiter: u8,
+                    on_int: F,
+                ) -> ReadIntsUntil<'_, Self, I, F>
+                where
+                    Self: Unpin,
+                    I: FromRadix10SignedChecked,
+                    F: FnMut(I),
+                {
+                    ReadIntsUntil {
+                        reader: self,
+                        parser: IntsUntilParser::new(delimiter, on_int),
+                    }
+                }
+            }
+
+            impl<R> AsyncBufReadIntegerExt for R where R: AsyncBufRead + ?Sized {}
+
+            /// Future returned by [`AsyncBufReadIntegerExt::read_int`].
+            #[must_use = "futures do nothing unless you `.await` or poll them"]
+            pub struct ReadInt<'a, R: ?Sized, I> {
+                reader: &'a mut R,
+                parser: IntParser<I>,
+            }
+
+            // The parser is never pinned.
+            impl<R: ?Sized, I> Unpin for ReadInt<'_, R, I> {}
+
+            impl<R, I> Future for ReadInt<'_, R, I>
+            where
+                R: AsyncBufRead + Unpin + ?Sized,
+                I: FromRadix10SignedChecked,
+            {
+                type Output = io::Result<I>;
+
+                fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+                    let this = self.get_mut();
+                    poll_parse(this.reader, cx, &mut this.parser)
+                }
+            }
+
+            /// Future returned by [`AsyncBufReadIntegerExt::read_ints_until`].
+            #[must_use = "futures do nothing unless you `.await` or poll them"]
+            pub struct ReadIntsUntil<'a, R: ?Sized, I, F> {
+                reader: &'a mut R,
+                parser: IntsUntilParser<I, F>,
+            }
+
+            // The parser is never pinned.
+            impl<R: ?Sized, I, F> Unpin for ReadIntsUntil<'_, R, I, F> {}
+
+            impl<R, I, F> Future for ReadIntsUntil<'_, R, I, F>
+            where
+                R: AsyncBufRead + Unpin + ?Sized,
+                I: FromRadix10SignedChecked,
+                F: FnMut(I),
+            {
+                type Output = io::Result<usize>;
+
+                fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+                    let this = self.get_mut();
+                    poll_parse(this.reader, cx, &mut this.parser)
+                }
+            }
+
+            fn poll_parse<R, P>(
+                reader: &mut R,
+                cx: &mut Context<'_>,
+                parser: &mut P,
+            ) -> Poll<io::Result<P::Output>>
+            where
+                R: AsyncBufRead + Unpin + ?Sized,
+                P: BufferedParser,
+            {
+                loop {
+                    let buffer = match Pin::new(&mut *reader).poll_fill_buf(cx) {
+                        Poll::Ready(Ok(buffer)) => buffer,
+                        Poll::Ready(Err(error)) => return Poll::Ready(Err(error)),
+                        Poll::Pending => return Poll::Pending,
+                    };
+                    let (consumed, result) = parser.advance(buffer);
+                    Pin::new(&mut *reader).consume(consumed);
+                    if let Some(result) = result {
+                        return Poll::Ready(result);
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "tokio")]
+impl_async_buf_read_ext!(
+    tokio,
+    ::tokio::io::AsyncBufRead,
+    "Parsing integers from a [`tokio::io::AsyncBufRead`](::tokio::io::AsyncBufRead).
+Requires the `tokio` feature.
+
+```
+use atoi::tokio::AsyncBufReadIntegerExt;
+use tokio::io::BufReader;
+
+# futures_executor::block_on(async {
+let mut reader = BufReader::with_capacity(2, &b\"12345 -6\"[..]);
+assert_eq!(12345u32, reader.read_int().await.unwrap());
+assert_eq!(-6i8, reader.read_int().await.unwrap());
+# });
+```"
+);
+
+#[cfg(feature = "futures-io")]
+impl_async_buf_read_ext!(
+    futures_io,
+    ::futures_io::AsyncBufRead,
+    "Parsing integers from a [`futures_io::AsyncBufRead`](::futures_io::AsyncBufRead).
+Requires the `futures-io` feature.
+
+```
+use atoi::futures_io::AsyncBufReadIntegerExt;
+use futures_util::io::BufReader;
+
+# futures_executor::block_on(async {
+let mut reader = BufReader::with_capacity(2, &b\"1 2 3\\n4\"[..]);
+let mut row: Vec<i32> = Vec::new();
+assert_eq!(3, reader.read_ints_until(b'\\n', |n| row.push(n)).await.unwrap());
+assert_eq!(vec![1, 2, 3], row);
+# });
+```"
+);
+
+#[cfg(all(test, feature = "tokio"))]
+mod tests {
+    use std::io::ErrorKind;
+
+    use ::tokio::io::BufReader;
+
+    use crate::tokio::AsyncBufReadIntegerExt;
+
+    #[test]
+    fn read_integers_from_tokio_async_buf_read() {
+        futures_executor::block_on(async {
+            let mut reader = BufReader::with_capacity(3, &b"\n-1234567\n1 2 x"[..]);
+            assert_eq!(-1234567, reader.read_int::<i32>().await.unwrap());
+            let mut ints: Vec<u8> = Vec::new();
+            assert_eq!(
+                0,
+                reader
+                    .read_ints_until(b'\n', |n| ints.push(n))
+                    .await
+                    .unwrap()
+            );
+            let error = reader
+                .read_ints_until(b'\n', |n| ints.push(n))
+                .await
+                .unwrap_err();
+            assert_eq!(ErrorKind::InvalidData, error.kind());
+            assert_eq!(vec![1u8, 2], ints);
+            let mut reader = BufReader::new(&b" \t"[..]);
+            let error = reader.read_int::<u8>().await.unwrap_err();
+            assert_eq!(ErrorKind::UnexpectedEof, error.kind());
+        });
+    }
+}
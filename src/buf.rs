@@ -0,0 +1,156 @@
+//! Parsing integers from a possibly non-contiguous [`Buf`].
+
+use bytes::Buf;
+
+use crate::{
+    Accumulator, DecimalAccumulator, FromRadix10Checked, FromRadix10SignedChecked,
+    FromRadix16Checked, HexAccumulator, ParseIntegerError,
+    error::{signed_result, unsigned_result},
+};
+
+/// Extends [`Buf`] with methods parsing integers and advancing the buffer past them. Numbers may
+/// span several chunks of the buffer.
+///
+/// The buffer is advanced by the bytes belonging to the number, even if an error is returned.
+/// Error positions are relative to the start of the number.
+///
+/// # Example
+///
+/// ```
+/// use atoi::BufIntegerExt;
+/// use bytes::Buf;
+///
+/// let mut buf = (&b"-12"[..]).chain(&b"34,ff"[..]);
+/// assert_eq!(Ok(-1234), buf.get_dec_int::<i32>());
+/// buf.advance(1);
+/// assert_eq!(Ok(0xff), buf.get_hex_uint::<u8>());
+/// assert!(!buf.has_remaining());
+/// ```
+pub trait BufIntegerExt: Buf {
+    /// Parses a decimal integer without sign. Acts like
+    /// [`crate::FromRadix10Checked::try_from_radix_10_checked`].
+    fn get_dec_uint<I>(&mut self) -> Result<I, ParseIntegerError>
+    where
+        I: FromRadix10Checked,
+    {
+        get_chunked(
+            self,
+            false,
+            I::from_radix_10_checked,
+            DecimalAccumulator::new,
+        )
+    }
+
+    /// Parses a decimal integer with an optional sign. Acts like
+    /// [`crate::FromRadix10SignedChecked::try_from_radix_10_signed_checked`].
+    fn get_dec_int<I>(&mut self) -> Result<I, ParseIntegerError>
+    where
+        I: FromRadix10SignedChecked,
+    {
+        get_chunked(
+            self,
+            true,
+            I::from_radix_10_signed_checked,
+            DecimalAccumulator::signed,
+        )
+    }
+
+    /// Parses a hexadecimal integer without sign. Acts like
+    /// [`crate::FromRadix16Checked::try_from_radix_16_checked`].
+    fn get_hex_uint<I>(&mut self) -> Result<I, ParseIntegerError>
+    where
+        I: FromRadix16Checked,
+    {
+        get_chunked(self, false, I::from_radix_16_checked, HexAccumulator::new)
+    }
+}
+
+impl<B> BufIntegerExt for B where B: Buf + ?Sized {}
+
+/// Parses the number using `parse_chunk` if it is contained in the first chunk of `buf`. Otherwise
+/// falls back to accumulating the digits chunk by chunk, using the accumulator created by
+/// `new_accumulator`.
+fn get_chunked<B, I, const RADIX: u32>(
+    buf: &mut B,
+    signed: bool,
+    parse_chunk: fn(&[u8]) -> (Option<I>, usize),
+    new_accumulator: fn() -> Accumulator<I, RADIX>,
+) -> Result<I, ParseIntegerError>
+where
+    B: Buf + ?Sized,
+{
+    let chunk = buf.chunk();
+    let (number, used) = parse_chunk(chunk);
+    if used != chunk.len() || chunk.len() == buf.remaining() {
+        let result = if signed {
+            signed_result(chunk, (number, used))
+        } else {
+            unsigned_result(chunk, (number, used))
+        };
+        buf.advance(used);
+        return result.map(|(number, _)| number);
+    }
+
+    // The number may continue in the next chunk.
+    let mut accumulator = new_accumulator();
+    while buf.has_remaining() {
+        let chunk = buf.chunk();
+        let len = chunk.len();
+        // Overflows are reported by `finish`, once all digits of the number have been consumed.
+        match accumulator.feed(chunk) {
+            Ok(Some(used)) => {
+                buf.advance(used);
+                break;
+            }
+            Ok(None) | Err(_) => buf.advance(len),
+        }
+    }
+    accumulator.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Integer;
+
+    #[test]
+    fn parse_integers_from_buf() {
+        // Contained in the first chunk
+        let mut buf = (&b"-42 7"[..]).chain(&b"ff"[..]);
+        assert_eq!(Ok(-42), buf.get_dec_int::<i8>());
+        assert_eq!(4, buf.remaining());
+        // Spanning both chunks
+        buf.advance(1);
+        assert_eq!(Ok(0x7ff), buf.get_hex_uint::<u16>());
+        assert!(!buf.has_remaining());
+        assert_eq!(Err(ParseIntegerError::Empty), buf.get_dec_uint::<u8>());
+
+        let mut buf = (&b"-"[..]).chain(&b"x"[..]);
+        assert_eq!(
+            Err(ParseIntegerError::InvalidDigit { position: 1 }),
+            buf.get_dec_int::<i32>()
+        );
+        assert_eq!(b"x", buf.chunk());
+        let mut buf = (&b"-"[..]).chain(&b""[..]);
+        assert_eq!(Err(ParseIntegerError::SignOnly), buf.get_dec_int::<i32>());
+        let mut buf = (&b"12"[..]).chain(&b"8,"[..]);
+        assert_eq!(Err(ParseIntegerError::PosOverflow), buf.get_dec_int::<i8>());
+        assert_eq!(b",", buf.chunk());
+        let mut buf = (&b"-1"[..]).chain(&b"29"[..]);
+        assert_eq!(Err(ParseIntegerError::NegOverflow), buf.get_dec_int::<i8>());
+        let mut buf = (&b"x1"[..]).chain(&b"2"[..]);
+        assert_eq!(
+            Err(ParseIntegerError::InvalidDigit { position: 0 }),
+            buf.get_hex_uint::<u8>()
+        );
+        assert_eq!(3, buf.remaining());
+
+        let mut buf = (&b"2"[..]).chain(&b"55"[..]);
+        assert_eq!(
+            Ok(core::num::NonZeroU8::MAX),
+            buf.get_dec_uint::<core::num::NonZeroU8>()
+        );
+        let mut buf = (&b"-3"[..]).chain(&b"2768"[..]);
+        assert_eq!(Ok(i16::MIN), buf.get_dec_int::<Integer<i16>>().map(|n| n.0));
+    }
+}
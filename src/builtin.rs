@@ -4,13 +4,18 @@
 //! A generic implementation for "any integer" can still be invoked using the `Integer` wrapper.
 
 use crate::{
-    FromDigit, FromHexDigit, FromRadix10, FromRadix10Checked, FromRadix10Signed,
-    FromRadix10SignedChecked, FromRadix16, FromRadix16Checked, Sign,
+    FromConstRadix, FromConstRadixChecked, FromConstRadixSigned, FromConstRadixSignedChecked,
+    FromDigit, FromHexDigit, FromRadix, FromRadix10, FromRadix10Checked, FromRadix10Saturating,
+    FromRadix10Signed, FromRadix10SignedChecked, FromRadix10SignedSaturating, FromRadix16,
+    FromRadix16Checked, FromRadix16Saturating, FromRadix16Signed, FromRadix16SignedChecked,
+    FromRadix16SignedSaturating, FromRadixChecked, FromRadixDigit, FromRadixSaturating,
+    FromRadixSigned, FromRadixSignedChecked, FromRadixSignedSaturating, Integer, MaxNumDigits,
+    ParseIntegerError, Sign, ToRadix10, ToRadix16, assert_const_radix, assert_radix, exact, swar,
 };
 
 use num_traits::FromPrimitive;
 
-use core::cmp::min;
+use core::{cmp::min, num::NonZero};
 
 macro_rules! impl_traits_using_integer {
     ($t:ident) => {
@@ -19,6 +24,15 @@ macro_rules! impl_traits_using_integer {
             fn from_radix_10(text: &[u8]) -> (Self, usize) {
                 let mut index = 0;
                 let mut number = 0;
+                // Types wide enough to hold eight digits, consume them eight at a time, as long as
+                // possible. The branch is resolved at compile time.
+                if $t::NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_10 >= 8 {
+                    while let Some(digits) = swar::eight_digits(&text[index..]) {
+                        number *= 100_000_000u32 as $t;
+                        number += digits as $t;
+                        index += 8;
+                    }
+                }
                 while index != text.len() {
                     if let Some(digit) = $t::from_digit(text[index]) {
                         number *= 10;
@@ -52,6 +66,13 @@ macro_rules! impl_traits_using_integer {
 
                 match sign {
                     Sign::Plus => {
+                        if $t::NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_10 >= 8 {
+                            while let Some(digits) = swar::eight_digits(&text[index..]) {
+                                number *= 100_000_000u32 as $t;
+                                number += digits as $t;
+                                index += 8;
+                            }
+                        }
                         while index != text.len() {
                             if let Some(digit) = $t::from_digit(text[index]) {
                                 number *= 10;
@@ -63,6 +84,13 @@ macro_rules! impl_traits_using_integer {
                         }
                     }
                     Sign::Minus => {
+                        if $t::NUM_SAFE_DIGITS_NON_POSITIVE_RADIX_10 >= 8 {
+                            while let Some(digits) = swar::eight_digits(&text[index..]) {
+                                number *= 100_000_000u32 as $t;
+                                number -= digits as $t;
+                                index += 8;
+                            }
+                        }
                         while index != text.len() {
                             if let Some(digit) = $t::from_digit(text[index]) {
                                 number *= 10;
@@ -98,6 +126,19 @@ macro_rules! impl_traits_using_integer {
                 }
                 (number, index)
             }
+
+            #[inline]
+            fn try_from_fixed_width_radix_10_checked<const N: usize>(
+                field: &[u8; N],
+            ) -> Result<Self, ParseIntegerError> {
+                match swar::fixed_width_digits(field) {
+                    Some(number) => {
+                        $t::try_from(number).map_err(|_| ParseIntegerError::PosOverflow)
+                    }
+                    // Invalid fields are rare, so we take the slow path to find out why.
+                    None => exact::fixed_width_radix_10(field),
+                }
+            }
         }
 
         impl FromRadix10SignedChecked for $t {
@@ -124,6 +165,15 @@ macro_rules! impl_traits_using_integer {
                             text.len(),
                             $t::NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_10 + offset,
                         );
+                        if $t::NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_10 >= 8 {
+                            while let Some(digits) =
+                                swar::eight_digits(&text[index..max_safe_index])
+                            {
+                                number *= 100_000_000u32 as $t;
+                                number += digits as $t;
+                                index += 8;
+                            }
+                        }
                         while index != max_safe_index {
                             if let Some(digit) = $t::from_digit(text[index]) {
                                 number *= 10;
@@ -151,6 +201,15 @@ macro_rules! impl_traits_using_integer {
                             text.len(),
                             $t::NUM_SAFE_DIGITS_NON_POSITIVE_RADIX_10 + offset,
                         );
+                        if $t::NUM_SAFE_DIGITS_NON_POSITIVE_RADIX_10 >= 8 {
+                            while let Some(digits) =
+                                swar::eight_digits(&text[index..max_safe_index])
+                            {
+                                number *= 100_000_000u32 as $t;
+                                number -= digits as $t;
+                                index += 8;
+                            }
+                        }
                         while index != max_safe_index {
                             if let Some(digit) = $t::from_digit(text[index]) {
                                 number *= 10;
@@ -216,6 +275,509 @@ macro_rules! impl_traits_using_integer {
             }
         }
 
+        impl FromRadix16Signed for $t {
+            #[inline]
+            fn from_radix_16_signed(text: &[u8]) -> (Self, usize) {
+                let mut index;
+                let mut number = 0;
+
+                let (sign, offset) = text
+                    .first()
+                    .and_then(|&byte| Sign::try_from(byte))
+                    .map(|sign| (sign, 1))
+                    .unwrap_or((Sign::Plus, 0));
+
+                index = offset;
+
+                // Having two dedicated loops for both the negative and the nonnegative case is rather
+                // verbose, yet performed up to 40% better then a more terse single loop with
+                // `number += digit * signum`.
+
+                match sign {
+                    Sign::Plus => {
+                        while index != text.len() {
+                            if let Some(digit) = $t::from_hex_digit(text[index]) {
+                                number *= 16;
+                                number += digit;
+                                index += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                    }
+                    Sign::Minus => {
+                        while index != text.len() {
+                            if let Some(digit) = $t::from_hex_digit(text[index]) {
+                                number *= 16;
+                                number -= digit;
+                                index += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                    }
+                }
+
+                (number, index)
+            }
+        }
+
+        impl FromRadix16SignedChecked for $t {
+            #[inline]
+            fn from_radix_16_signed_checked(text: &[u8]) -> (Option<Self>, usize) {
+                let mut index;
+                let mut number = 0;
+
+                let (sign, offset) = text
+                    .first()
+                    .and_then(|&byte| Sign::try_from(byte))
+                    .map(|sign| (sign, 1))
+                    .unwrap_or((Sign::Plus, 0));
+
+                index = offset;
+
+                // Having two dedicated loops for both the negative and the nonnegative case is rather
+                // verbose, yet performed up to 40% better then a more terse single loop with
+                // `number += digit * signum`.
+
+                match sign {
+                    Sign::Plus => {
+                        let max_safe_index = min(
+                            text.len(),
+                            $t::NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_16 + offset,
+                        );
+                        while index != max_safe_index {
+                            if let Some(digit) = $t::from_hex_digit(text[index]) {
+                                number *= 16;
+                                number += digit;
+                                index += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                        // We parsed the digits, which do not need checking now lets see the next one:
+                        let mut number = Some(number);
+                        while index != text.len() {
+                            if let Some(digit) = $t::from_hex_digit(text[index]) {
+                                number = number.and_then(|n| n.checked_mul(16));
+                                number = number.and_then(|n| n.checked_add(digit));
+                                index += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                        (number, index)
+                    }
+                    Sign::Minus => {
+                        let max_safe_index = min(
+                            text.len(),
+                            $t::NUM_SAFE_DIGITS_NON_POSITIVE_RADIX_16 + offset,
+                        );
+                        while index != max_safe_index {
+                            if let Some(digit) = $t::from_hex_digit(text[index]) {
+                                number *= 16;
+                                number -= digit;
+                                index += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                        // We parsed the digits, which do not need checking now lets see the next one:
+                        let mut number = Some(number);
+                        while index != text.len() {
+                            if let Some(digit) = $t::from_hex_digit(text[index]) {
+                                number = number.and_then(|n| n.checked_mul(16));
+                                number = number.and_then(|n| n.checked_sub(digit));
+                                index += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                        (number, index)
+                    }
+                }
+            }
+        }
+
+        impl FromRadix for $t {
+            #[inline]
+            fn from_radix(text: &[u8], radix: u32) -> (Self, usize) {
+                assert_radix(radix);
+                let mut index = 0;
+                let mut number = 0;
+                while index != text.len() {
+                    if let Some(digit) = $t::from_radix_digit(text[index], radix) {
+                        number *= radix as $t;
+                        number += digit;
+                        index += 1;
+                    } else {
+                        break;
+                    }
+                }
+                (number, index)
+            }
+        }
+
+        impl FromRadixChecked for $t {
+            #[inline]
+            fn from_radix_checked(text: &[u8], radix: u32) -> (Option<Self>, usize) {
+                assert_radix(radix);
+                let (number, mut index) = $t::from_radix(
+                    &text[..min(text.len(), $t::NUM_SAFE_DIGITS_NON_NEGATIVE[radix as usize])],
+                    radix,
+                );
+                let mut number = Some(number);
+                // We parsed the digits, which do not need checking now lets see the next one:
+                while index != text.len() {
+                    if let Some(digit) = $t::from_radix_digit(text[index], radix) {
+                        number = number.and_then(|n| n.checked_mul(radix as $t));
+                        number = number.and_then(|n| n.checked_add(digit));
+                        index += 1;
+                    } else {
+                        break;
+                    }
+                }
+                (number, index)
+            }
+        }
+
+        impl FromRadixSigned for $t {
+            #[inline]
+            fn from_radix_signed(text: &[u8], radix: u32) -> (Self, usize) {
+                assert_radix(radix);
+                let mut index;
+                let mut number = 0;
+
+                let (sign, offset) = text
+                    .first()
+                    .and_then(|&byte| Sign::try_from(byte))
+                    .map(|sign| (sign, 1))
+                    .unwrap_or((Sign::Plus, 0));
+
+                index = offset;
+
+                match sign {
+                    Sign::Plus => {
+                        while index != text.len() {
+                            if let Some(digit) = $t::from_radix_digit(text[index], radix) {
+                                number *= radix as $t;
+                                number += digit;
+                                index += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                    }
+                    Sign::Minus => {
+                        while index != text.len() {
+                            if let Some(digit) = $t::from_radix_digit(text[index], radix) {
+                                number *= radix as $t;
+                                number -= digit;
+                                index += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                    }
+                }
+
+                (number, index)
+            }
+        }
+
+        impl FromRadixSignedChecked for $t {
+            #[inline]
+            fn from_radix_signed_checked(text: &[u8], radix: u32) -> (Option<Self>, usize) {
+                assert_radix(radix);
+                let mut index;
+                let mut number = 0;
+
+                let (sign, offset) = text
+                    .first()
+                    .and_then(|&byte| Sign::try_from(byte))
+                    .map(|sign| (sign, 1))
+                    .unwrap_or((Sign::Plus, 0));
+
+                index = offset;
+
+                match sign {
+                    Sign::Plus => {
+                        let max_safe_index = min(
+                            text.len(),
+                            $t::NUM_SAFE_DIGITS_NON_NEGATIVE[radix as usize] + offset,
+                        );
+                        while index != max_safe_index {
+                            if let Some(digit) = $t::from_radix_digit(text[index], radix) {
+                                number *= radix as $t;
+                                number += digit;
+                                index += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                        // We parsed the digits, which do not need checking now lets see the next one:
+                        let mut number = Some(number);
+                        while index != text.len() {
+                            if let Some(digit) = $t::from_radix_digit(text[index], radix) {
+                                number = number.and_then(|n| n.checked_mul(radix as $t));
+                                number = number.and_then(|n| n.checked_add(digit));
+                                index += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                        (number, index)
+                    }
+                    Sign::Minus => {
+                        let max_safe_index = min(
+                            text.len(),
+                            $t::NUM_SAFE_DIGITS_NON_POSITIVE[radix as usize] + offset,
+                        );
+                        while index != max_safe_index {
+                            if let Some(digit) = $t::from_radix_digit(text[index], radix) {
+                                number *= radix as $t;
+                                number -= digit;
+                                index += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                        // We parsed the digits, which do not need checking now lets see the next one:
+                        let mut number = Some(number);
+                        while index != text.len() {
+                            if let Some(digit) = $t::from_radix_digit(text[index], radix) {
+                                number = number.and_then(|n| n.checked_mul(radix as $t));
+                                number = number.and_then(|n| n.checked_sub(digit));
+                                index += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                        (number, index)
+                    }
+                }
+            }
+        }
+
+        impl FromConstRadix for $t {
+            #[inline]
+            fn from_const_radix<const RADIX: u32>(text: &[u8]) -> (Self, usize) {
+                assert_const_radix::<RADIX>();
+                let mut index = 0;
+                let mut number = 0;
+                while index != text.len() {
+                    if let Some(digit) = $t::from_radix_digit(text[index], RADIX) {
+                        number *= RADIX as $t;
+                        number += digit;
+                        index += 1;
+                    } else {
+                        break;
+                    }
+                }
+                (number, index)
+            }
+        }
+
+        impl FromConstRadixChecked for $t {
+            #[inline]
+            fn from_const_radix_checked<const RADIX: u32>(text: &[u8]) -> (Option<Self>, usize) {
+                assert_const_radix::<RADIX>();
+                let num_safe_digits = const { num_safe_digits($t::MAX_MAGNITUDE, RADIX as u128) };
+                let (number, mut index) =
+                    $t::from_const_radix::<RADIX>(&text[..min(text.len(), num_safe_digits)]);
+                let mut number = Some(number);
+                // We parsed the digits, which do not need checking now lets see the next one:
+                while index != text.len() {
+                    if let Some(digit) = $t::from_radix_digit(text[index], RADIX) {
+                        number = number.and_then(|n| n.checked_mul(RADIX as $t));
+                        number = number.and_then(|n| n.checked_add(digit));
+                        index += 1;
+                    } else {
+                        break;
+                    }
+                }
+                (number, index)
+            }
+        }
+
+        impl FromConstRadixSigned for $t {
+            #[inline]
+            fn from_const_radix_signed<const RADIX: u32>(text: &[u8]) -> (Self, usize) {
+                assert_const_radix::<RADIX>();
+                let mut index;
+                let mut number = 0;
+
+                let (sign, offset) = text
+                    .first()
+                    .and_then(|&byte| Sign::try_from(byte))
+                    .map(|sign| (sign, 1))
+                    .unwrap_or((Sign::Plus, 0));
+
+                index = offset;
+
+                match sign {
+                    Sign::Plus => {
+                        while index != text.len() {
+                            if let Some(digit) = $t::from_radix_digit(text[index], RADIX) {
+                                number *= RADIX as $t;
+                                number += digit;
+                                index += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                    }
+                    Sign::Minus => {
+                        while index != text.len() {
+                            if let Some(digit) = $t::from_radix_digit(text[index], RADIX) {
+                                number *= RADIX as $t;
+                                number -= digit;
+                                index += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                    }
+                }
+
+                (number, index)
+            }
+        }
+
+        impl FromConstRadixSignedChecked for $t {
+            #[inline]
+            fn from_const_radix_signed_checked<const RADIX: u32>(
+                text: &[u8],
+            ) -> (Option<Self>, usize) {
+                assert_const_radix::<RADIX>();
+                let mut index;
+                let mut number = 0;
+
+                let (sign, offset) = text
+                    .first()
+                    .and_then(|&byte| Sign::try_from(byte))
+                    .map(|sign| (sign, 1))
+                    .unwrap_or((Sign::Plus, 0));
+
+                index = offset;
+
+                match sign {
+                    Sign::Plus => {
+                        let num_safe_digits =
+                            const { num_safe_digits($t::MAX_MAGNITUDE, RADIX as u128) };
+                        let max_safe_index = min(text.len(), num_safe_digits + offset);
+                        while index != max_safe_index {
+                            if let Some(digit) = $t::from_radix_digit(text[index], RADIX) {
+                                number *= RADIX as $t;
+                                number += digit;
+                                index += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                        // We parsed the digits, which do not need checking now lets see the next one:
+                        let mut number = Some(number);
+                        while index != text.len() {
+                            if let Some(digit) = $t::from_radix_digit(text[index], RADIX) {
+                                number = number.and_then(|n| n.checked_mul(RADIX as $t));
+                                number = number.and_then(|n| n.checked_add(digit));
+                                index += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                        (number, index)
+                    }
+                    Sign::Minus => {
+                        let num_safe_digits =
+                            const { num_safe_digits($t::MIN_MAGNITUDE, RADIX as u128) };
+                        let max_safe_index = min(text.len(), num_safe_digits + offset);
+                        while index != max_safe_index {
+                            if let Some(digit) = $t::from_radix_digit(text[index], RADIX) {
+                                number *= RADIX as $t;
+                                number -= digit;
+                                index += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                        // We parsed the digits, which do not need checking now lets see the next one:
+                        let mut number = Some(number);
+                        while index != text.len() {
+                            if let Some(digit) = $t::from_radix_digit(text[index], RADIX) {
+                                number = number.and_then(|n| n.checked_mul(RADIX as $t));
+                                number = number.and_then(|n| n.checked_sub(digit));
+                                index += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                        (number, index)
+                    }
+                }
+            }
+        }
+
+        impl FromRadix10Saturating for $t {
+            #[inline]
+            fn from_radix_10_saturating(text: &[u8]) -> (Self, usize) {
+                let (number, used) = $t::from_radix_10_checked(text);
+                (number.unwrap_or($t::MAX), used)
+            }
+        }
+
+        impl FromRadix10SignedSaturating for $t {
+            #[inline]
+            fn from_radix_10_signed_saturating(text: &[u8]) -> (Self, usize) {
+                let (number, used) = $t::from_radix_10_signed_checked(text);
+                let bound = match text.first() {
+                    Some(b'-') => $t::MIN,
+                    _ => $t::MAX,
+                };
+                (number.unwrap_or(bound), used)
+            }
+        }
+
+        impl FromRadix16Saturating for $t {
+            #[inline]
+            fn from_radix_16_saturating(text: &[u8]) -> (Self, usize) {
+                let (number, used) = $t::from_radix_16_checked(text);
+                (number.unwrap_or($t::MAX), used)
+            }
+        }
+
+        impl FromRadix16SignedSaturating for $t {
+            #[inline]
+            fn from_radix_16_signed_saturating(text: &[u8]) -> (Self, usize) {
+                let (number, used) = $t::from_radix_16_signed_checked(text);
+                let bound = match text.first() {
+                    Some(b'-') => $t::MIN,
+                    _ => $t::MAX,
+                };
+                (number.unwrap_or(bound), used)
+            }
+        }
+
+        impl FromRadixSaturating for $t {
+            #[inline]
+            fn from_radix_saturating(text: &[u8], radix: u32) -> (Self, usize) {
+                let (number, used) = $t::from_radix_checked(text, radix);
+                (number.unwrap_or($t::MAX), used)
+            }
+        }
+
+        impl FromRadixSignedSaturating for $t {
+            #[inline]
+            fn from_radix_signed_saturating(text: &[u8], radix: u32) -> (Self, usize) {
+                let (number, used) = $t::from_radix_signed_checked(text, radix);
+                let bound = match text.first() {
+                    Some(b'-') => $t::MIN,
+                    _ => $t::MAX,
+                };
+                (number.unwrap_or(bound), used)
+            }
+        }
+
         impl FromDigit for $t {
             #[inline]
             fn from_digit(digit: u8) -> Option<Self> {
@@ -256,6 +818,26 @@ macro_rules! impl_traits_using_integer {
                 }
             }
         }
+
+        impl FromRadixDigit for $t {
+            #[inline]
+            fn from_radix_digit(digit: u8, radix: u32) -> Option<Self> {
+                // `to_digit` maps both lowercase and uppercase letters to the digits 10 to 35.
+                (digit as char).to_digit(radix).map(|digit| digit as $t)
+            }
+        }
+
+        impl MaxNumDigits for $t {
+            #[inline]
+            fn max_num_digits(radix: $t) -> usize {
+                Integer::<$t>::max_num_digits(Integer(radix))
+            }
+
+            #[inline]
+            fn max_num_digits_negative(radix: $t) -> usize {
+                Integer::<$t>::max_num_digits_negative(Integer(radix))
+            }
+        }
     };
 }
 
@@ -269,100 +851,284 @@ impl_traits_using_integer!(i64);
 impl_traits_using_integer!(u64);
 impl_traits_using_integer!(i128);
 impl_traits_using_integer!(u128);
+impl_traits_using_integer!(isize);
+impl_traits_using_integer!(usize);
 
-// Num digits which are safe to parse without overflow
-trait SafeDigits {
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_10: usize;
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_16: usize;
-    const NUM_SAFE_DIGITS_NON_POSITIVE_RADIX_10: usize;
-}
+// Parsing into a non zero integer type forwards to the implementation of the underlying primitive
+// and rejects zero afterwards. Only the unsigned checked traits are implemented, since they can
+// indicate a zero with `None`. The unchecked traits have no way to report a zero, or that no digits
+// have been found, so they are left out. The signed checked traits are left out, too, since they
+// require the unchecked signed traits.
+macro_rules! impl_traits_using_non_zero {
+    ($t:ident) => {
+        impl FromRadix10Checked for NonZero<$t> {
+            #[inline]
+            fn from_radix_10_checked(text: &[u8]) -> (Option<Self>, usize) {
+                let (number, index) = $t::from_radix_10_checked(text);
+                (number.and_then(NonZero::new), index)
+            }
 
-impl SafeDigits for i8 {
-    // 127
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_10: usize = 2;
-    // 7F
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_16: usize = 1;
-    // -128
-    const NUM_SAFE_DIGITS_NON_POSITIVE_RADIX_10: usize = 2;
-}
+            #[inline]
+            fn try_from_radix_10_checked(text: &[u8]) -> Result<(Self, usize), ParseIntegerError> {
+                let (number, index) = $t::try_from_radix_10_checked(text)?;
+                NonZero::new(number)
+                    .map(|number| (number, index))
+                    .ok_or(ParseIntegerError::Zero)
+            }
 
-impl SafeDigits for u8 {
-    // 255
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_10: usize = 2;
-    // FF
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_16: usize = 2;
-    // 0
-    const NUM_SAFE_DIGITS_NON_POSITIVE_RADIX_10: usize = 0;
-}
+            #[inline]
+            fn try_from_fixed_width_radix_10_checked<const N: usize>(
+                field: &[u8; N],
+            ) -> Result<Self, ParseIntegerError> {
+                let number = $t::try_from_fixed_width_radix_10_checked(field)?;
+                NonZero::new(number).ok_or(ParseIntegerError::Zero)
+            }
+        }
 
-impl SafeDigits for i16 {
-    // 32767
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_10: usize = 4;
-    // 7FFF
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_16: usize = 3;
-    // -32768
-    const NUM_SAFE_DIGITS_NON_POSITIVE_RADIX_10: usize = 5;
-}
+        impl FromRadix16Checked for NonZero<$t> {
+            #[inline]
+            fn from_radix_16_checked(text: &[u8]) -> (Option<Self>, usize) {
+                let (number, index) = $t::from_radix_16_checked(text);
+                (number.and_then(NonZero::new), index)
+            }
 
-impl SafeDigits for u16 {
-    // 65535
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_10: usize = 4;
-    // FFFF
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_16: usize = 4;
-    // 0
-    const NUM_SAFE_DIGITS_NON_POSITIVE_RADIX_10: usize = 0;
-}
+            #[inline]
+            fn try_from_radix_16_checked(text: &[u8]) -> Result<(Self, usize), ParseIntegerError> {
+                let (number, index) = $t::try_from_radix_16_checked(text)?;
+                NonZero::new(number)
+                    .map(|number| (number, index))
+                    .ok_or(ParseIntegerError::Zero)
+            }
+        }
+
+        impl FromRadixChecked for NonZero<$t> {
+            #[inline]
+            fn from_radix_checked(text: &[u8], radix: u32) -> (Option<Self>, usize) {
+                let (number, index) = $t::from_radix_checked(text, radix);
+                (number.and_then(NonZero::new), index)
+            }
+        }
+
+        impl FromConstRadixChecked for NonZero<$t> {
+            #[inline]
+            fn from_const_radix_checked<const RADIX: u32>(text: &[u8]) -> (Option<Self>, usize) {
+                let (number, index) = $t::from_const_radix_checked::<RADIX>(text);
+                (number.and_then(NonZero::new), index)
+            }
+        }
+
+        impl ToRadix10 for NonZero<$t> {
+            #[inline]
+            fn to_radix_10(self, buffer: &mut [u8]) -> usize {
+                self.get().to_radix_10(buffer)
+            }
+        }
 
-impl SafeDigits for i32 {
-    // 2147483647
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_10: usize = 9;
-    // 7FFFFFFF
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_16: usize = 7;
-    // -2147483648
-    const NUM_SAFE_DIGITS_NON_POSITIVE_RADIX_10: usize = 9;
+        impl ToRadix16 for NonZero<$t> {
+            #[inline]
+            fn to_radix_16(self, buffer: &mut [u8]) -> usize {
+                self.get().to_radix_16(buffer)
+            }
+        }
+    };
 }
 
-impl SafeDigits for u32 {
-    // 4294967295
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_10: usize = 9;
-    // FFFFFFFF
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_16: usize = 8;
-    // 0
-    const NUM_SAFE_DIGITS_NON_POSITIVE_RADIX_10: usize = 0;
+impl_traits_using_non_zero!(i8);
+impl_traits_using_non_zero!(u8);
+impl_traits_using_non_zero!(i16);
+impl_traits_using_non_zero!(u16);
+impl_traits_using_non_zero!(i32);
+impl_traits_using_non_zero!(u32);
+impl_traits_using_non_zero!(i64);
+impl_traits_using_non_zero!(u64);
+impl_traits_using_non_zero!(i128);
+impl_traits_using_non_zero!(u128);
+impl_traits_using_non_zero!(isize);
+impl_traits_using_non_zero!(usize);
+
+// Num digits which are safe to parse without overflow. Computed at compile time from the bounds of
+// the integer type, so supporting a new type only requires stating its bounds.
+trait SafeDigits {
+    // Largest value representable by the type
+    const MAX_MAGNITUDE: u128;
+    // Absolute value of the smallest value representable by the type
+    const MIN_MAGNITUDE: u128;
+
+    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_10: usize = num_safe_digits(Self::MAX_MAGNITUDE, 10);
+    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_16: usize = num_safe_digits(Self::MAX_MAGNITUDE, 16);
+    const NUM_SAFE_DIGITS_NON_POSITIVE_RADIX_10: usize = num_safe_digits(Self::MIN_MAGNITUDE, 10);
+    const NUM_SAFE_DIGITS_NON_POSITIVE_RADIX_16: usize = num_safe_digits(Self::MIN_MAGNITUDE, 16);
+    // Indexed by radix. Entries for radix 0 and 1 are unused.
+    const NUM_SAFE_DIGITS_NON_NEGATIVE: [usize; 37] = num_safe_digits_by_radix(Self::MAX_MAGNITUDE);
+    const NUM_SAFE_DIGITS_NON_POSITIVE: [usize; 37] = num_safe_digits_by_radix(Self::MIN_MAGNITUDE);
 }
 
-impl SafeDigits for i64 {
-    // 9223372036854775807
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_10: usize = 18;
-    // 7FFFFFFFFFFFFFFF
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_16: usize = 15;
-    // -9223372036854775808
-    const NUM_SAFE_DIGITS_NON_POSITIVE_RADIX_10: usize = 18;
+/// Number of digits in a positional numeral system with radix `radix`, which can be parsed into a
+/// number without exceeding `bound`.
+const fn num_safe_digits(bound: u128, radix: u128) -> usize {
+    let mut num_digits = 0;
+    // Largest number which can be represented using `num_digits` digits.
+    let mut largest: u128 = 0;
+    loop {
+        let next = match largest.checked_mul(radix) {
+            Some(next) => next,
+            None => return num_digits,
+        };
+        let next = match next.checked_add(radix - 1) {
+            Some(next) => next,
+            None => return num_digits,
+        };
+        if next > bound {
+            return num_digits;
+        }
+        largest = next;
+        num_digits += 1;
+    }
 }
 
-impl SafeDigits for u64 {
-    // 18446744073709551615
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_10: usize = 19;
-    // FFFFFFFFFFFFFFFF
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_16: usize = 16;
-    // 0
-    const NUM_SAFE_DIGITS_NON_POSITIVE_RADIX_10: usize = 0;
+/// Tabulates [`num_safe_digits`] for all radices from 2 to 36.
+const fn num_safe_digits_by_radix(bound: u128) -> [usize; 37] {
+    let mut table = [0; 37];
+    let mut radix = 2;
+    while radix != table.len() {
+        table[radix] = num_safe_digits(bound, radix as u128);
+        radix += 1;
+    }
+    table
 }
 
-impl SafeDigits for i128 {
-    // 170141183460469231731687303715884105727
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_10: usize = 38;
-    // 7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_16: usize = 31;
-    // -170141183460469231731687303715884105728
-    const NUM_SAFE_DIGITS_NON_POSITIVE_RADIX_10: usize = 38;
+macro_rules! impl_safe_digits {
+    ($t:ident) => {
+        impl SafeDigits for $t {
+            const MAX_MAGNITUDE: u128 = $t::MAX.abs_diff(0) as u128;
+            const MIN_MAGNITUDE: u128 = $t::MIN.abs_diff(0) as u128;
+        }
+    };
 }
 
-impl SafeDigits for u128 {
-    // 340282366920938463463374607431768211455
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_10: usize = 38;
-    // FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
-    const NUM_SAFE_DIGITS_NON_NEGATIVE_RADIX_16: usize = 32;
-    // 0
-    const NUM_SAFE_DIGITS_NON_POSITIVE_RADIX_10: usize = 0;
+impl_safe_digits!(i8);
+impl_safe_digits!(u8);
+impl_safe_digits!(i16);
+impl_safe_digits!(u16);
+impl_safe_digits!(i32);
+impl_safe_digits!(u32);
+impl_safe_digits!(i64);
+impl_safe_digits!(u64);
+impl_safe_digits!(i128);
+impl_safe_digits!(u128);
+impl_safe_digits!(isize);
+impl_safe_digits!(usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{atoi, parse_exact_radix_10};
+
+    #[test]
+    fn pointer_sized_parsing() {
+        let mut buffer = [0; 40];
+        let len = usize::MAX.to_radix_10(&mut buffer);
+        assert_eq!(Some(usize::MAX), atoi::<usize>(&buffer[..len]));
+        buffer[len] = b'0';
+        assert_eq!(None, atoi::<usize>(&buffer[..=len]));
+        let len = isize::MIN.to_radix_10(&mut buffer);
+        assert_eq!(Some(isize::MIN), atoi::<isize>(&buffer[..len]));
+        assert_eq!(None, atoi::<usize>(b"-1"));
+    }
+
+    #[test]
+    fn non_zero_parsing() {
+        use core::num::{NonZeroI8, NonZeroU32, NonZeroUsize};
+
+        assert_eq!(
+            (NonZeroU32::new(42), 2),
+            NonZeroU32::from_radix_10_checked(b"42")
+        );
+        assert_eq!((None, 1), NonZeroU32::from_radix_10_checked(b"0"));
+        assert_eq!(
+            (NonZeroI8::new(127), 3),
+            NonZeroI8::from_radix_10_checked(b"127")
+        );
+        assert_eq!((None, 3), NonZeroI8::from_radix_10_checked(b"000"));
+        assert_eq!((None, 0), NonZeroUsize::from_radix_16_checked(b"x"));
+        assert_eq!(
+            (NonZeroUsize::new(255), 2),
+            NonZeroUsize::from_radix_16_checked(b"ff")
+        );
+        assert_eq!(
+            Err(ParseIntegerError::Zero),
+            NonZeroU32::try_from_radix_10_checked(b"0")
+        );
+        assert_eq!(
+            Err(ParseIntegerError::PosOverflow),
+            NonZeroI8::try_from_radix_10_checked(b"128")
+        );
+        assert_eq!(
+            Err(ParseIntegerError::InvalidDigit { position: 0 }),
+            NonZeroU32::try_from_radix_16_checked(b"x")
+        );
+        assert_eq!(
+            Ok(NonZeroU32::new(10).unwrap()),
+            parse_exact_radix_10(b"10")
+        );
+    }
+
+    #[test]
+    fn non_zero_parsing_without_digits_or_zero() {
+        use core::num::NonZeroU32;
+
+        for text in [&b""[..], b"x", b"0"] {
+            let used = text.iter().take_while(|byte| byte.is_ascii_digit()).count();
+            assert_eq!((None, used), NonZeroU32::from_radix_10_checked(text));
+            assert_eq!((None, used), NonZeroU32::from_radix_16_checked(text));
+            assert_eq!((None, used), NonZeroU32::from_radix_checked(text, 8));
+            assert_eq!(
+                (None, used),
+                NonZeroU32::from_const_radix_checked::<2>(text)
+            );
+        }
+        assert_eq!(
+            Err(ParseIntegerError::Empty),
+            NonZeroU32::try_from_radix_10_checked(b"")
+        );
+        assert_eq!(
+            Err(ParseIntegerError::InvalidDigit { position: 0 }),
+            NonZeroU32::try_from_radix_10_checked(b"x")
+        );
+        assert_eq!(
+            Err(ParseIntegerError::Zero),
+            NonZeroU32::try_from_radix_16_checked(b"0")
+        );
+    }
+
+    #[test]
+    fn saturating_parsing() {
+        assert_eq!(
+            (u64::MAX, 20),
+            u64::from_radix_10_saturating(b"18446744073709551616")
+        );
+        assert_eq!(
+            (u128::MAX, 33),
+            u128::from_radix_16_saturating(b"100000000000000000000000000000000")
+        );
+        assert_eq!(
+            (i16::MIN, 6),
+            i16::from_radix_10_signed_saturating(b"-32769")
+        );
+        assert_eq!(
+            (i16::MIN, 6),
+            i16::from_radix_10_signed_saturating(b"-32768")
+        );
+        assert_eq!(
+            (i16::MAX, 6),
+            i16::from_radix_10_signed_saturating(b"+32768")
+        );
+        assert_eq!((0, 1), i16::from_radix_10_signed_saturating(b"-"));
+        assert_eq!(
+            (i16::MIN, 5),
+            i16::from_radix_16_signed_saturating(b"-8001")
+        );
+        assert_eq!((u8::MAX, 9), u8::from_radix_saturating(b"100000000", 2));
+        assert_eq!((i8::MAX, 5), i8::from_radix_signed_saturating(b"+1000", 8));
+    }
 }
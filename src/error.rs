@@ -0,0 +1,94 @@
+use core::fmt::{self, Display, Formatter};
+
+use crate::Sign;
+
+/// Describes why an integer could not be parsed from a slice.
+///
+/// Returned by [`crate::try_atoi`] and the `try_*` methods of the checked parsing traits.
+/// More variants may be added in the future, so matching on it requires a wildcard arm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseIntegerError {
+    /// The slice is empty.
+    Empty,
+    /// The byte at `position` is not a digit, yet a digit is required at this position.
+    InvalidDigit {
+        /// Index of the offending byte within the slice.
+        position: usize,
+    },
+    /// The number is too large to be represented by the integer type.
+    PosOverflow,
+    /// The number is too small to be represented by the integer type. This includes any negative
+    /// number other than zero for unsigned types.
+    NegOverflow,
+    /// The slice starts with a sign, which is not followed by any digit.
+    SignOnly,
+    /// The number is zero, which can not be represented by a non zero integer type.
+    Zero,
+}
+
+impl Display for ParseIntegerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseIntegerError::Empty => write!(f, "cannot parse integer from empty slice"),
+            ParseIntegerError::InvalidDigit { position } => {
+                write!(f, "invalid digit found at position {position}")
+            }
+            ParseIntegerError::PosOverflow => {
+                write!(f, "number too large to fit in target type")
+            }
+            ParseIntegerError::NegOverflow => {
+                write!(f, "number too small to fit in target type")
+            }
+            ParseIntegerError::SignOnly => write!(f, "sign is not followed by any digit"),
+            ParseIntegerError::Zero => write!(f, "number would be zero for non-zero type"),
+        }
+    }
+}
+
+impl core::error::Error for ParseIntegerError {}
+
+impl ParseIntegerError {
+    /// Adjusts the position of an invalid digit, for errors reported for a subslice starting at
+    /// `offset`.
+    pub(crate) fn shifted(self, offset: usize) -> Self {
+        match self {
+            ParseIntegerError::InvalidDigit { position } => ParseIntegerError::InvalidDigit {
+                position: offset + position,
+            },
+            other => other,
+        }
+    }
+}
+
+/// Interprets the result of parsing `text` with one of the checked traits, which do not allow for
+/// a sign.
+pub(crate) fn unsigned_result<I>(
+    text: &[u8],
+    (number, used): (Option<I>, usize),
+) -> Result<(I, usize), ParseIntegerError> {
+    match (number, used) {
+        (_, 0) if text.is_empty() => Err(ParseIntegerError::Empty),
+        (_, 0) => Err(ParseIntegerError::InvalidDigit { position: 0 }),
+        (None, _) => Err(ParseIntegerError::PosOverflow),
+        (Some(number), used) => Ok((number, used)),
+    }
+}
+
+/// Interprets the result of parsing `text` with one of the checked traits, which allow for an
+/// optional leading sign.
+pub(crate) fn signed_result<I>(
+    text: &[u8],
+    (number, used): (Option<I>, usize),
+) -> Result<(I, usize), ParseIntegerError> {
+    let sign = text.first().and_then(|&byte| Sign::try_from(byte));
+    let offset = if sign.is_some() { 1 } else { 0 };
+    match (number, used) {
+        _ if text.is_empty() => Err(ParseIntegerError::Empty),
+        _ if text.len() == offset => Err(ParseIntegerError::SignOnly),
+        (_, used) if used == offset => Err(ParseIntegerError::InvalidDigit { position: offset }),
+        (None, _) if sign == Some(Sign::Minus) => Err(ParseIntegerError::NegOverflow),
+        (None, _) => Err(ParseIntegerError::PosOverflow),
+        (Some(number), used) => Ok((number, used)),
+    }
+}
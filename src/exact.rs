@@ -0,0 +1,244 @@
+//! Parsing functions which require the entire slice to be consumed by the number.
+
+use crate::{
+    FromRadix10Checked, FromRadix10SignedChecked, FromRadix16Checked, FromRadix16SignedChecked,
+    ParseIntegerError,
+};
+
+/// Parses an integer from a slice, which must not contain anything besides the number. Acts like
+/// [`crate::atoi`], but rejects any trailing bytes.
+///
+/// # Example
+///
+/// ```
+/// use atoi::{parse_exact, ParseIntegerError};
+/// assert_eq!(Ok(42), parse_exact::<u32>(b"42"));
+/// // Signs are allowed
+/// assert_eq!(Ok(-42), parse_exact::<i32>(b"-42"));
+/// // Trailing bytes are reported as invalid digit
+/// assert_eq!(
+///     Err(ParseIntegerError::InvalidDigit { position: 2 }),
+///     parse_exact::<u32>(b"42 is the answer")
+/// );
+/// assert_eq!(Err(ParseIntegerError::Empty), parse_exact::<u32>(b""));
+/// assert_eq!(Err(ParseIntegerError::PosOverflow), parse_exact::<u8>(b"256"));
+/// ```
+#[inline]
+pub fn parse_exact<I>(text: impl AsRef<[u8]>) -> Result<I, ParseIntegerError>
+where
+    I: FromRadix10SignedChecked,
+{
+    let text = text.as_ref();
+    exact(text, I::try_from_radix_10_signed_checked(text))
+}
+
+/// Parses an integer from a slice, which must not contain anything besides the digits of the
+/// number. Acts like [`FromRadix10Checked::try_from_radix_10_checked`], but rejects any trailing
+/// bytes.
+///
+/// # Example
+///
+/// ```
+/// use atoi::{parse_exact_radix_10, ParseIntegerError};
+/// assert_eq!(Ok(42), parse_exact_radix_10::<u32>(b"42"));
+/// // Signs are not allowed
+/// assert_eq!(
+///     Err(ParseIntegerError::InvalidDigit { position: 0 }),
+///     parse_exact_radix_10::<i32>(b"+42")
+/// );
+/// assert_eq!(
+///     Err(ParseIntegerError::InvalidDigit { position: 2 }),
+///     parse_exact_radix_10::<u32>(b"42,")
+/// );
+/// ```
+#[inline]
+pub fn parse_exact_radix_10<I>(text: impl AsRef<[u8]>) -> Result<I, ParseIntegerError>
+where
+    I: FromRadix10Checked,
+{
+    let text = text.as_ref();
+    exact(text, I::try_from_radix_10_checked(text))
+}
+
+/// Parses an integer from a slice, which must not contain anything besides the hexadecimal digits
+/// of the number. Acts like [`FromRadix16Checked::try_from_radix_16_checked`], but rejects any
+/// trailing bytes.
+///
+/// # Example
+///
+/// ```
+/// use atoi::{parse_exact_radix_16, ParseIntegerError};
+/// assert_eq!(Ok(42), parse_exact_radix_16::<u32>(b"2A"));
+/// assert_eq!(
+///     Err(ParseIntegerError::InvalidDigit { position: 2 }),
+///     parse_exact_radix_16::<u32>(b"2ag")
+/// );
+/// ```
+#[inline]
+pub fn parse_exact_radix_16<I>(text: impl AsRef<[u8]>) -> Result<I, ParseIntegerError>
+where
+    I: FromRadix16Checked,
+{
+    let text = text.as_ref();
+    exact(text, I::try_from_radix_16_checked(text))
+}
+
+/// Parses an integer from a slice, which must not contain anything besides an optional sign and
+/// the hexadecimal digits of the number. Acts like
+/// [`FromRadix16SignedChecked::try_from_radix_16_signed_checked`], but rejects any trailing bytes.
+///
+/// # Example
+///
+/// ```
+/// use atoi::{parse_exact_radix_16_signed, ParseIntegerError};
+/// assert_eq!(Ok(-31), parse_exact_radix_16_signed::<i32>(b"-1f"));
+/// assert_eq!(
+///     Err(ParseIntegerError::InvalidDigit { position: 3 }),
+///     parse_exact_radix_16_signed::<i32>(b"-1f ")
+/// );
+/// ```
+#[inline]
+pub fn parse_exact_radix_16_signed<I>(text: impl AsRef<[u8]>) -> Result<I, ParseIntegerError>
+where
+    I: FromRadix16SignedChecked,
+{
+    let text = text.as_ref();
+    exact(text, I::try_from_radix_16_signed_checked(text))
+}
+
+/// Parses an integer from a fixed width field of `N` bytes, as found in many record oriented
+/// formats. The field may be padded with leading spaces or zeros, but every byte after the padding
+/// must be an ASCII digit. For the integer types native to Rust, knowing the width at compile time
+/// allows for validating and parsing the field eight bytes at a time, without branching on
+/// individual bytes. Other types are parsed by
+/// [`FromRadix10Checked::try_from_fixed_width_radix_10_checked`].
+///
+/// # Example
+///
+/// ```
+/// use atoi::{from_fixed_width_radix_10, ParseIntegerError};
+/// assert_eq!(Ok(42), from_fixed_width_radix_10::<u64, 8>(b"00000042"));
+/// assert_eq!(Ok(42), from_fixed_width_radix_10::<u64, 8>(b"      42"));
+/// // Padding must precede the digits
+/// assert_eq!(
+///     Err(ParseIntegerError::InvalidDigit { position: 2 }),
+///     from_fixed_width_radix_10::<u64, 8>(b"42      ")
+/// );
+/// // A field consisting only of padding is empty
+/// assert_eq!(Err(ParseIntegerError::Empty), from_fixed_width_radix_10::<u64, 4>(b"    "));
+/// assert_eq!(Err(ParseIntegerError::PosOverflow), from_fixed_width_radix_10::<u8, 4>(b" 256"));
+/// ```
+#[inline]
+pub fn from_fixed_width_radix_10<I, const N: usize>(field: &[u8; N]) -> Result<I, ParseIntegerError>
+where
+    I: FromRadix10Checked,
+{
+    I::try_from_fixed_width_radix_10_checked(field)
+}
+
+/// Parses a fixed width field byte by byte. Default for
+/// [`FromRadix10Checked::try_from_fixed_width_radix_10_checked`], and used by faster
+/// implementations to tell why a field is invalid.
+#[inline]
+pub(crate) fn fixed_width_radix_10<I>(field: &[u8]) -> Result<I, ParseIntegerError>
+where
+    I: FromRadix10Checked,
+{
+    let num_padding = field.iter().take_while(|&&byte| byte == b' ').count();
+    let digits = &field[num_padding..];
+    if digits.is_empty() {
+        return Err(ParseIntegerError::Empty);
+    }
+    parse_exact_radix_10(digits).map_err(|error| error.shifted(num_padding))
+}
+
+/// Turns a successfully parsed number into an error, if it did not consume the entire `text`.
+#[inline]
+fn exact<I>(
+    text: &[u8],
+    result: Result<(I, usize), ParseIntegerError>,
+) -> Result<I, ParseIntegerError> {
+    match result? {
+        (number, used) if used == text.len() => Ok(number),
+        (_, used) => Err(ParseIntegerError::InvalidDigit { position: used }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_parsing() {
+        assert_eq!(Ok(-128), parse_exact::<i8>(b"-128"));
+        assert_eq!(Err(ParseIntegerError::SignOnly), parse_exact::<i8>(b"-"));
+        assert_eq!(
+            Err(ParseIntegerError::InvalidDigit { position: 3 }),
+            parse_exact::<i8>(b"-12-")
+        );
+        // Overflow takes precedence over trailing bytes
+        assert_eq!(
+            Err(ParseIntegerError::NegOverflow),
+            parse_exact::<i8>(b"-129 ")
+        );
+        assert_eq!(Ok(255), parse_exact_radix_10::<u8>(b"255"));
+        assert_eq!(Ok(255), parse_exact_radix_16::<u8>(b"ff"));
+        assert_eq!(Ok(-128), parse_exact_radix_16_signed::<i8>(b"-80"));
+    }
+
+    #[test]
+    fn fixed_width_parsing() {
+        assert_eq!(
+            Ok(12_345_678_901_234_567_890),
+            from_fixed_width_radix_10::<u64, 20>(b"12345678901234567890")
+        );
+        assert_eq!(Ok(0), from_fixed_width_radix_10::<u32, 8>(b"       0"));
+        assert_eq!(Ok(0), from_fixed_width_radix_10::<u32, 8>(b"00000000"));
+        assert_eq!(
+            Err(ParseIntegerError::InvalidDigit { position: 4 }),
+            from_fixed_width_radix_10::<u32, 8>(b"  12 345")
+        );
+        assert_eq!(
+            Err(ParseIntegerError::InvalidDigit { position: 2 }),
+            from_fixed_width_radix_10::<i32, 8>(b"  -12345")
+        );
+        assert_eq!(
+            Err(ParseIntegerError::Empty),
+            from_fixed_width_radix_10::<u32, 0>(b"")
+        );
+        // Fields not a multiple of eight bytes wide
+        assert_eq!(
+            Ok(1_234_567),
+            from_fixed_width_radix_10::<u64, 9>(b"  1234567")
+        );
+        assert_eq!(
+            Ok(255),
+            from_fixed_width_radix_10::<u8, 12>(b"         255")
+        );
+        assert_eq!(
+            Err(ParseIntegerError::PosOverflow),
+            from_fixed_width_radix_10::<u8, 12>(b"         256")
+        );
+        assert_eq!(
+            Err(ParseIntegerError::InvalidDigit { position: 10 }),
+            from_fixed_width_radix_10::<u32, 12>(b"        12x4")
+        );
+        assert_eq!(
+            Err(ParseIntegerError::InvalidDigit { position: 4 }),
+            from_fixed_width_radix_10::<u32, 10>(b"  00 00012")
+        );
+        // Fields too wide for every number to fit into `u128`
+        assert_eq!(
+            Ok(12_345_678_901_234_567_890_123_456_789_012_345_678),
+            from_fixed_width_radix_10::<u128, 40>(b"  12345678901234567890123456789012345678")
+        );
+        assert_eq!(
+            Err(ParseIntegerError::PosOverflow),
+            from_fixed_width_radix_10::<u128, 40>(b"9999999999999999999999999999999999999999")
+        );
+        assert_eq!(
+            Err(ParseIntegerError::Zero),
+            from_fixed_width_radix_10::<core::num::NonZeroU16, 9>(b"  0000000")
+        );
+    }
+}
@@ -0,0 +1,80 @@
+//! Implementation for the floating point types native to Rust. We only determine the extent of the
+//! number within the slice and leave the actual conversion to the standard library, which already
+//! implements correct rounding. Since every byte of a number is ASCII, this avoids validating the
+//! entire slice as UTF-8.
+
+use core::str;
+
+use crate::FromRadix10Float;
+
+macro_rules! impl_float {
+    ($t:ident) => {
+        impl FromRadix10Float for $t {
+            #[inline]
+            fn from_radix_10_float(text: &[u8]) -> (Self, usize) {
+                let len = float_len(text);
+                // All bytes of the number are ASCII, so decoding can not fail. Should the standard
+                // library still reject the number, we report that nothing has been parsed rather
+                // than claiming the bytes for a zero.
+                match str::from_utf8(&text[..len]).map(str::parse) {
+                    Ok(Ok(number)) => (number, len),
+                    _ => (0., 0),
+                }
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
+
+/// Length of the longest prefix of `text`, which is a floating point number. I.e. an optional sign
+/// followed by either `inf`, `infinity`, `nan` (regardless of case) or by digits with an optional
+/// decimal point and an optional exponent.
+fn float_len(text: &[u8]) -> usize {
+    let mut index = match text.first() {
+        Some(b'+' | b'-') => 1,
+        _ => 0,
+    };
+
+    for special in [&b"infinity"[..], b"inf", b"nan"] {
+        if text.len() - index >= special.len()
+            && text[index..index + special.len()].eq_ignore_ascii_case(special)
+        {
+            return index + special.len();
+        }
+    }
+
+    let num_integer_digits = num_digits(&text[index..]);
+    index += num_integer_digits;
+    let mut num_fraction_digits = 0;
+    if text.get(index) == Some(&b'.') {
+        num_fraction_digits = num_digits(&text[index + 1..]);
+        // A decimal point is only part of the number, if there is a digit on either side.
+        if num_integer_digits + num_fraction_digits != 0 {
+            index += 1 + num_fraction_digits;
+        }
+    }
+    if num_integer_digits + num_fraction_digits == 0 {
+        return 0;
+    }
+
+    if matches!(text.get(index), Some(b'e' | b'E')) {
+        let mut exponent_index = index + 1;
+        if matches!(text.get(exponent_index), Some(b'+' | b'-')) {
+            exponent_index += 1;
+        }
+        let num_exponent_digits = num_digits(&text[exponent_index..]);
+        // An exponent without digits is not part of the number.
+        if num_exponent_digits != 0 {
+            index = exponent_index + num_exponent_digits;
+        }
+    }
+
+    index
+}
+
+/// Number of ASCII digits at the start of `text`
+fn num_digits(text: &[u8]) -> usize {
+    text.iter().take_while(|byte| byte.is_ascii_digit()).count()
+}
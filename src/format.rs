@@ -0,0 +1,64 @@
+//! Formatting of the integer types native to Rust.
+
+use num_traits::{AsPrimitive, PrimInt};
+
+use crate::{ToRadix10, ToRadix16};
+
+macro_rules! impl_to_radix {
+    ($t:ident) => {
+        impl ToRadix10 for $t {
+            #[inline]
+            fn to_radix_10(self, buffer: &mut [u8]) -> usize {
+                write_magnitude(self < 0 as $t, self.abs_diff(0), 10, buffer)
+            }
+        }
+
+        impl ToRadix16 for $t {
+            #[inline]
+            fn to_radix_16(self, buffer: &mut [u8]) -> usize {
+                write_magnitude(self < 0 as $t, self.abs_diff(0), 16, buffer)
+            }
+        }
+    };
+}
+
+impl_to_radix!(i8);
+impl_to_radix!(u8);
+impl_to_radix!(i16);
+impl_to_radix!(u16);
+impl_to_radix!(i32);
+impl_to_radix!(u32);
+impl_to_radix!(i64);
+impl_to_radix!(u64);
+impl_to_radix!(i128);
+impl_to_radix!(u128);
+impl_to_radix!(isize);
+impl_to_radix!(usize);
+
+/// Writes `magnitude` to the start of `buffer`, preceded by a `-` if `negative` is true. Returns the
+/// number of bytes written.
+#[inline]
+fn write_magnitude<U>(negative: bool, mut magnitude: U, radix: u8, buffer: &mut [u8]) -> usize
+where
+    U: PrimInt + AsPrimitive<usize> + 'static,
+    u8: AsPrimitive<U>,
+{
+    let radix: U = radix.as_();
+    let mut len = 0;
+    if negative {
+        buffer[0] = b'-';
+        len = 1;
+    }
+    // Digits are generated starting with the least significant one, so we reverse them afterwards.
+    let first_digit = len;
+    loop {
+        buffer[len] = b"0123456789abcdef"[(magnitude % radix).as_()];
+        len += 1;
+        magnitude = magnitude / radix;
+        if magnitude.is_zero() {
+            break;
+        }
+    }
+    buffer[first_digit..len].reverse();
+    len
+}
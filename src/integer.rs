@@ -1,18 +1,32 @@
 use core::{
     cmp::{max, min},
-    ops::{AddAssign, DivAssign, MulAssign, SubAssign},
+    ops::{AddAssign, Div, DivAssign, MulAssign, Rem, Sub, SubAssign},
 };
 
-use num_traits::{Bounded, CheckedAdd, CheckedMul, CheckedSub, One, Zero};
+use num_traits::{Bounded, CheckedAdd, CheckedMul, CheckedSub, One, ToPrimitive, Zero};
 
 use crate::{
-    FromDigit, FromRadix10, FromRadix10Checked, FromRadix10Signed, FromRadix10SignedChecked,
-    FromRadix16, FromRadix16Checked, MaxNumDigits, Sign,
+    FromConstRadix, FromConstRadixChecked, FromConstRadixSigned, FromConstRadixSignedChecked,
+    FromDigit, FromRadix, FromRadix10, FromRadix10Checked, FromRadix10Saturating,
+    FromRadix10Signed, FromRadix10SignedChecked, FromRadix10SignedSaturating, FromRadix16,
+    FromRadix16Checked, FromRadix16Saturating, FromRadix16Signed, FromRadix16SignedChecked,
+    FromRadix16SignedSaturating, FromRadixChecked, FromRadixSaturating, FromRadixSigned,
+    FromRadixSignedChecked, FromRadixSignedSaturating, MaxNumDigits, Sign, ToRadix10, ToRadix16,
+    assert_const_radix, assert_radix,
 };
 
-/// Wrapper which implements the traits [`crate::FromRadix10`], [`crate::FromRadix10Checked`],
-/// [`crate::FromRadix16`] and [`crate::FromRadix16Checked`] for any inductive type. I.e. a type
-/// implementing [`One`], [`Zero`], `+=` and `*=`.
+/// Wrapper which implements the parsing traits for any inductive type. I.e. a type implementing
+/// [`One`], [`Zero`], `+=` and `*=`. These are [`crate::FromRadix10`], [`crate::FromRadix16`],
+/// [`crate::FromRadix`] and [`crate::FromConstRadix`], each together with its `Signed`, `Checked`
+/// and `SignedChecked` variant, e.g. [`crate::FromRadix16SignedChecked`].
+///
+/// If the type is also [`Bounded`] the wrapper implements the saturating traits
+/// [`crate::FromRadix10Saturating`], [`crate::FromRadix10SignedSaturating`],
+/// [`crate::FromRadix16Saturating`], [`crate::FromRadix16SignedSaturating`],
+/// [`crate::FromRadixSaturating`] and [`crate::FromRadixSignedSaturating`], as well as
+/// [`crate::MaxNumDigits`]. For types supporting `/` and `%` it implements [`crate::ToRadix10`] and
+/// [`crate::ToRadix16`].
+#[derive(Clone, Copy)]
 pub struct Integer<I>(pub I);
 
 impl<I> FromRadix10 for Integer<I>
@@ -291,6 +305,432 @@ where
     }
 }
 
+impl<I> FromRadix16Signed for Integer<I>
+where
+    I: Zero + One + AddAssign + SubAssign + MulAssign,
+{
+    fn from_radix_16_signed(text: &[u8]) -> (Self, usize) {
+        let mut index;
+        let mut number = I::zero();
+
+        let (sign, offset) = text
+            .first()
+            .and_then(|&byte| Sign::try_from(byte))
+            .map(|sign| (sign, 1))
+            .unwrap_or((Sign::Plus, 0));
+
+        index = offset;
+
+        // Having two dedicated loops for both the negative and the nonnegative case is rather
+        // verbose, yet performed up to 40% better then a more terse single loop with
+        // `number += digit * signum`.
+
+        match sign {
+            Sign::Plus => {
+                while index != text.len() {
+                    if let Some(digit) = ascii_to_hexdigit(text[index]) {
+                        number *= nth(16);
+                        number += digit;
+                        index += 1;
+                    } else {
+                        break;
+                    }
+                }
+            }
+            Sign::Minus => {
+                while index != text.len() {
+                    if let Some(digit) = ascii_to_hexdigit(text[index]) {
+                        number *= nth(16);
+                        number -= digit;
+                        index += 1;
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+
+        (Integer(number), index)
+    }
+}
+
+impl<I> FromRadix16SignedChecked for Integer<I>
+where
+    I: Zero + One + AddAssign + MulAssign + SubAssign + CheckedAdd + CheckedSub + CheckedMul,
+    Integer<I>: MaxNumDigits,
+{
+    fn from_radix_16_signed_checked(text: &[u8]) -> (Option<Self>, usize) {
+        let mut index;
+        let mut number = I::zero();
+
+        let (sign, offset) = text
+            .first()
+            .and_then(|&byte| Sign::try_from(byte))
+            .map(|sign| (sign, 1))
+            .unwrap_or((Sign::Plus, 0));
+
+        index = offset;
+
+        // Having two dedicated loops for both the negative and the nonnegative case is rather
+        // verbose, yet performed up to 40% better then a more terse single loop with
+        // `number += digit * signum`.
+
+        match sign {
+            Sign::Plus => {
+                let max_safe_digits = max(1, Integer::<I>::max_num_digits(Integer(nth(16)))) - 1;
+                let max_safe_index = min(text.len(), max_safe_digits + offset);
+                while index != max_safe_index {
+                    if let Some(digit) = ascii_to_hexdigit(text[index]) {
+                        number *= nth(16);
+                        number += digit;
+                        index += 1;
+                    } else {
+                        break;
+                    }
+                }
+                // We parsed the digits, which do not need checking now lets see the next one:
+                let mut number = Some(number);
+                while index != text.len() {
+                    if let Some(digit) = ascii_to_hexdigit(text[index]) {
+                        number = number.and_then(|n| n.checked_mul(&nth(16)));
+                        number = number.and_then(|n| n.checked_add(&digit));
+                        index += 1;
+                    } else {
+                        break;
+                    }
+                }
+                (number.map(Integer), index)
+            }
+            Sign::Minus => {
+                let max_safe_digits =
+                    max(1, Integer::<I>::max_num_digits_negative(Integer(nth(16)))) - 1;
+                let max_safe_index = min(text.len(), max_safe_digits + offset);
+                while index != max_safe_index {
+                    if let Some(digit) = ascii_to_hexdigit(text[index]) {
+                        number *= nth(16);
+                        number -= digit;
+                        index += 1;
+                    } else {
+                        break;
+                    }
+                }
+                // We parsed the digits, which do not need checking now lets see the next one:
+                let mut number = Some(number);
+                while index != text.len() {
+                    if let Some(digit) = ascii_to_hexdigit(text[index]) {
+                        number = number.and_then(|n| n.checked_mul(&nth(16)));
+                        number = number.and_then(|n| n.checked_sub(&digit));
+                        index += 1;
+                    } else {
+                        break;
+                    }
+                }
+                (number.map(Integer), index)
+            }
+        }
+    }
+}
+
+/// Converts an ascii character to a digit of a numeral system with radix `radix`
+fn ascii_to_radix_digit<I>(character: u8, radix: u32) -> Option<I>
+where
+    I: Zero + One,
+{
+    (character as char)
+        .to_digit(radix)
+        .map(|digit| nth(digit as u8))
+}
+
+impl<I> FromRadix for Integer<I>
+where
+    I: Zero + One + AddAssign + MulAssign,
+{
+    fn from_radix(text: &[u8], radix: u32) -> (Self, usize) {
+        assert_radix(radix);
+        let mut index = 0;
+        let mut number = I::zero();
+        while index != text.len() {
+            if let Some(digit) = ascii_to_radix_digit(text[index], radix) {
+                number *= nth(radix as u8);
+                number += digit;
+                index += 1;
+            } else {
+                break;
+            }
+        }
+        (Integer(number), index)
+    }
+}
+
+impl<I> FromRadixChecked for Integer<I>
+where
+    I: Zero + One + AddAssign + MulAssign + CheckedMul + CheckedAdd,
+    Integer<I>: MaxNumDigits,
+{
+    fn from_radix_checked(text: &[u8], radix: u32) -> (Option<Self>, usize) {
+        assert_radix(radix);
+        let max_safe_digits = max(1, Integer::<I>::max_num_digits(Integer(nth(radix as u8)))) - 1;
+        let (Integer(number), mut index) =
+            Integer::<I>::from_radix(&text[..min(text.len(), max_safe_digits)], radix);
+        let mut number = Some(number);
+        // We parsed the digits, which do not need checking now lets see the next one:
+        while index != text.len() {
+            if let Some(digit) = ascii_to_radix_digit(text[index], radix) {
+                number = number.and_then(|n| n.checked_mul(&nth(radix as u8)));
+                number = number.and_then(|n| n.checked_add(&digit));
+                index += 1;
+            } else {
+                break;
+            }
+        }
+        (number.map(Integer), index)
+    }
+}
+
+impl<I> FromRadixSigned for Integer<I>
+where
+    I: Zero + One + AddAssign + SubAssign + MulAssign,
+{
+    fn from_radix_signed(text: &[u8], radix: u32) -> (Self, usize) {
+        assert_radix(radix);
+        let mut index;
+        let mut number = I::zero();
+
+        let (sign, offset) = text
+            .first()
+            .and_then(|&byte| Sign::try_from(byte))
+            .map(|sign| (sign, 1))
+            .unwrap_or((Sign::Plus, 0));
+
+        index = offset;
+
+        match sign {
+            Sign::Plus => {
+                while index != text.len() {
+                    if let Some(digit) = ascii_to_radix_digit(text[index], radix) {
+                        number *= nth(radix as u8);
+                        number += digit;
+                        index += 1;
+                    } else {
+                        break;
+                    }
+                }
+            }
+            Sign::Minus => {
+                while index != text.len() {
+                    if let Some(digit) = ascii_to_radix_digit(text[index], radix) {
+                        number *= nth(radix as u8);
+                        number -= digit;
+                        index += 1;
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+
+        (Integer(number), index)
+    }
+}
+
+impl<I> FromRadixSignedChecked for Integer<I>
+where
+    I: Zero + One + AddAssign + MulAssign + SubAssign + CheckedAdd + CheckedSub + CheckedMul,
+    Integer<I>: MaxNumDigits,
+{
+    fn from_radix_signed_checked(text: &[u8], radix: u32) -> (Option<Self>, usize) {
+        assert_radix(radix);
+        let mut index;
+        let mut number = I::zero();
+
+        let (sign, offset) = text
+            .first()
+            .and_then(|&byte| Sign::try_from(byte))
+            .map(|sign| (sign, 1))
+            .unwrap_or((Sign::Plus, 0));
+
+        index = offset;
+
+        match sign {
+            Sign::Plus => {
+                let max_safe_digits =
+                    max(1, Integer::<I>::max_num_digits(Integer(nth(radix as u8)))) - 1;
+                let max_safe_index = min(text.len(), max_safe_digits + offset);
+                while index != max_safe_index {
+                    if let Some(digit) = ascii_to_radix_digit(text[index], radix) {
+                        number *= nth(radix as u8);
+                        number += digit;
+                        index += 1;
+                    } else {
+                        break;
+                    }
+                }
+                // We parsed the digits, which do not need checking now lets see the next one:
+                let mut number = Some(number);
+                while index != text.len() {
+                    if let Some(digit) = ascii_to_radix_digit(text[index], radix) {
+                        number = number.and_then(|n| n.checked_mul(&nth(radix as u8)));
+                        number = number.and_then(|n| n.checked_add(&digit));
+                        index += 1;
+                    } else {
+                        break;
+                    }
+                }
+                (number.map(Integer), index)
+            }
+            Sign::Minus => {
+                let max_safe_digits = max(
+                    1,
+                    Integer::<I>::max_num_digits_negative(Integer(nth(radix as u8))),
+                ) - 1;
+                let max_safe_index = min(text.len(), max_safe_digits + offset);
+                while index != max_safe_index {
+                    if let Some(digit) = ascii_to_radix_digit(text[index], radix) {
+                        number *= nth(radix as u8);
+                        number -= digit;
+                        index += 1;
+                    } else {
+                        break;
+                    }
+                }
+                // We parsed the digits, which do not need checking now lets see the next one:
+                let mut number = Some(number);
+                while index != text.len() {
+                    if let Some(digit) = ascii_to_radix_digit(text[index], radix) {
+                        number = number.and_then(|n| n.checked_mul(&nth(radix as u8)));
+                        number = number.and_then(|n| n.checked_sub(&digit));
+                        index += 1;
+                    } else {
+                        break;
+                    }
+                }
+                (number.map(Integer), index)
+            }
+        }
+    }
+}
+
+// The generic implementation does not profit from knowing the radix at compile time, so the
+// implementations for a constant radix forward to the ones for a radix known at runtime.
+
+impl<I> FromConstRadix for Integer<I>
+where
+    Integer<I>: FromRadix,
+{
+    fn from_const_radix<const RADIX: u32>(text: &[u8]) -> (Self, usize) {
+        assert_const_radix::<RADIX>();
+        Self::from_radix(text, RADIX)
+    }
+}
+
+impl<I> FromConstRadixChecked for Integer<I>
+where
+    Integer<I>: FromRadixChecked,
+{
+    fn from_const_radix_checked<const RADIX: u32>(text: &[u8]) -> (Option<Self>, usize) {
+        assert_const_radix::<RADIX>();
+        Self::from_radix_checked(text, RADIX)
+    }
+}
+
+impl<I> FromConstRadixSigned for Integer<I>
+where
+    Integer<I>: FromRadixSigned,
+{
+    fn from_const_radix_signed<const RADIX: u32>(text: &[u8]) -> (Self, usize) {
+        assert_const_radix::<RADIX>();
+        Self::from_radix_signed(text, RADIX)
+    }
+}
+
+impl<I> FromConstRadixSignedChecked for Integer<I>
+where
+    Integer<I>: FromRadixSignedChecked,
+{
+    fn from_const_radix_signed_checked<const RADIX: u32>(text: &[u8]) -> (Option<Self>, usize) {
+        assert_const_radix::<RADIX>();
+        Self::from_radix_signed_checked(text, RADIX)
+    }
+}
+
+impl<I> FromRadix10Saturating for Integer<I>
+where
+    I: Bounded,
+    Integer<I>: FromRadix10Checked,
+{
+    fn from_radix_10_saturating(text: &[u8]) -> (Self, usize) {
+        let (number, used) = Self::from_radix_10_checked(text);
+        (number.unwrap_or(Integer(I::max_value())), used)
+    }
+}
+
+impl<I> FromRadix10SignedSaturating for Integer<I>
+where
+    I: Bounded,
+    Integer<I>: FromRadix10SignedChecked,
+{
+    fn from_radix_10_signed_saturating(text: &[u8]) -> (Self, usize) {
+        let (number, used) = Self::from_radix_10_signed_checked(text);
+        let bound = match text.first() {
+            Some(b'-') => I::min_value(),
+            _ => I::max_value(),
+        };
+        (number.unwrap_or(Integer(bound)), used)
+    }
+}
+
+impl<I> FromRadix16Saturating for Integer<I>
+where
+    I: Bounded,
+    Integer<I>: FromRadix16Checked,
+{
+    fn from_radix_16_saturating(text: &[u8]) -> (Self, usize) {
+        let (number, used) = Self::from_radix_16_checked(text);
+        (number.unwrap_or(Integer(I::max_value())), used)
+    }
+}
+
+impl<I> FromRadix16SignedSaturating for Integer<I>
+where
+    I: Bounded,
+    Integer<I>: FromRadix16SignedChecked,
+{
+    fn from_radix_16_signed_saturating(text: &[u8]) -> (Self, usize) {
+        let (number, used) = Self::from_radix_16_signed_checked(text);
+        let bound = match text.first() {
+            Some(b'-') => I::min_value(),
+            _ => I::max_value(),
+        };
+        (number.unwrap_or(Integer(bound)), used)
+    }
+}
+
+impl<I> FromRadixSaturating for Integer<I>
+where
+    I: Bounded,
+    Integer<I>: FromRadixChecked,
+{
+    fn from_radix_saturating(text: &[u8], radix: u32) -> (Self, usize) {
+        let (number, used) = Self::from_radix_checked(text, radix);
+        (number.unwrap_or(Integer(I::max_value())), used)
+    }
+}
+
+impl<I> FromRadixSignedSaturating for Integer<I>
+where
+    I: Bounded,
+    Integer<I>: FromRadixSignedChecked,
+{
+    fn from_radix_signed_saturating(text: &[u8], radix: u32) -> (Self, usize) {
+        let (number, used) = Self::from_radix_signed_checked(text, radix);
+        let bound = match text.first() {
+            Some(b'-') => I::min_value(),
+            _ => I::max_value(),
+        };
+        (number.unwrap_or(Integer(bound)), used)
+    }
+}
+
 impl<I> MaxNumDigits for Integer<I>
 where
     I: Bounded + Zero + DivAssign + Ord + Copy,
@@ -319,3 +759,77 @@ where
         d
     }
 }
+
+impl<I> ToRadix10 for Integer<I>
+where
+    I: Zero + One + PartialOrd + Copy + Sub<Output = I> + Div<Output = I> + Rem<Output = I>,
+    I: ToPrimitive,
+{
+    fn to_radix_10(self, buffer: &mut [u8]) -> usize {
+        write_digits(self.0, 10, buffer)
+    }
+}
+
+impl<I> ToRadix16 for Integer<I>
+where
+    I: Zero + One + PartialOrd + Copy + Sub<Output = I> + Div<Output = I> + Rem<Output = I>,
+    I: ToPrimitive,
+{
+    fn to_radix_16(self, buffer: &mut [u8]) -> usize {
+        write_digits(self.0, 16, buffer)
+    }
+}
+
+/// Writes `number` to the start of `buffer`, preceded by a `-` if it is negative. Returns the number
+/// of bytes written.
+fn write_digits<I>(mut number: I, radix: u8, buffer: &mut [u8]) -> usize
+where
+    I: Zero + One + PartialOrd + Copy + Sub<Output = I> + Div<Output = I> + Rem<Output = I>,
+    I: ToPrimitive,
+{
+    let negative = number < I::zero();
+    let radix: I = nth(radix);
+    let mut len = 0;
+    if negative {
+        buffer[0] = b'-';
+        len = 1;
+    }
+    // Digits are generated starting with the least significant one, so we reverse them afterwards.
+    // We never negate `number` itself, since its magnitude may not fit into `I`. The remainder of a
+    // negative number is negative or zero though, so we negate that instead.
+    let first_digit = len;
+    loop {
+        let remainder = number % radix;
+        let digit = if negative {
+            I::zero() - remainder
+        } else {
+            remainder
+        };
+        let digit = digit
+            .to_usize()
+            .expect("Digit must be smaller than the radix");
+        buffer[len] = b"0123456789abcdef"[digit];
+        len += 1;
+        number = number / radix;
+        if number.is_zero() {
+            break;
+        }
+    }
+    buffer[first_digit..len].reverse();
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saturating_parsing() {
+        let (Integer(number), used) = Integer::<i8>::from_radix_10_signed_saturating(b"-129");
+        assert_eq!((i8::MIN, 4), (number, used));
+        let (Integer(number), used) = Integer::<u8>::from_radix_16_saturating(b"100");
+        assert_eq!((u8::MAX, 3), (number, used));
+        let (Integer(number), used) = Integer::<i8>::from_radix_signed_saturating(b"-1000", 8);
+        assert_eq!((i8::MIN, 5), (number, used));
+    }
+}
@@ -0,0 +1,270 @@
+//! Parsing integers directly from the buffer of a [`BufRead`].
+
+use std::{
+    io::{self, BufRead},
+    mem,
+};
+
+use crate::{
+    DecimalAccumulator, FromRadix10SignedChecked, ParseIntegerError,
+    whitespace::{is_whitespace, leading_whitespace},
+};
+
+/// Extends [`BufRead`] with methods parsing decimal integers directly from its internal buffer,
+/// without copying the bytes into a `String` first. Numbers may span several refills of the
+/// buffer.
+///
+/// Parsing errors are reported as [`io::Error`]s wrapping a [`ParseIntegerError`]. Their kind is
+/// [`io::ErrorKind::UnexpectedEof`] if the end of the stream has been reached before any number,
+/// and [`io::ErrorKind::InvalidData`] otherwise.
+pub trait BufReadIntegerExt: BufRead {
+    /// Skips leading whitespace and parses a decimal integer with an optional sign. The first byte
+    /// after the number is not consumed. See [`crate::Whitespace`] for which bytes count as
+    /// whitespace.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::io::{BufReader, ErrorKind};
+    /// use atoi::BufReadIntegerExt;
+    ///
+    /// let mut reader = BufReader::with_capacity(2, &b" 12345\n-6"[..]);
+    /// assert_eq!(12345u32, reader.read_int().unwrap());
+    /// assert_eq!(-6i8, reader.read_int().unwrap());
+    /// let error = reader.read_int::<i8>().unwrap_err();
+    /// assert_eq!(ErrorKind::UnexpectedEof, error.kind());
+    /// ```
+    fn read_int<I>(&mut self) -> io::Result<I>
+    where
+        I: FromRadix10SignedChecked,
+    {
+        parse_buffered(self, IntParser::new())
+    }
+
+    /// Parses decimal integers separated by whitespace, until `delimiter` or the end of the stream
+    /// is reached. The delimiter is consumed. Each number is passed to `on_int`, and the
+    /// number of integers read is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::io::BufReader;
+    /// use atoi::BufReadIntegerExt;
+    ///
+    /// let mut reader = BufReader::new(&b"1 2\t3\n-4 5\n"[..]);
+    /// let mut row: Vec<i32> = Vec::new();
+    /// assert_eq!(3, reader.read_ints_until(b'\n', |n| row.push(n)).unwrap());
+    /// assert_eq!(2, reader.read_ints_until(b'\n', |n| row.push(n)).unwrap());
+    /// assert_eq!(0, reader.read_ints_until(b'\n', |n| row.push(n)).unwrap());
+    /// assert_eq!(vec![1, 2, 3, -4, 5], row);
+    /// ```
+    fn read_ints_until<I>(&mut self, delimiter: u8, on_int: impl FnMut(I)) -> io::Result<usize>
+    where
+        I: FromRadix10SignedChecked,
+    {
+        parse_buffered(self, IntsUntilParser::new(delimiter, on_int))
+    }
+}
+
+impl<R> BufReadIntegerExt for R where R: BufRead + ?Sized {}
+
+/// Parses from the buffers of a reader, one buffer at a time. Shared by the blocking and the
+/// asynchronous readers.
+pub(crate) trait BufferedParser {
+    type Output;
+
+    /// Inspects the next `buffer` returned by the reader. An empty buffer indicates the end of
+    /// the stream. Returns the number of bytes to consume and the result once parsing is done.
+    fn advance(&mut self, buffer: &[u8]) -> (usize, Option<io::Result<Self::Output>>);
+}
+
+fn parse_buffered<R, P>(reader: &mut R, mut parser: P) -> io::Result<P::Output>
+where
+    R: BufRead + ?Sized,
+    P: BufferedParser,
+{
+    loop {
+        let buffer = match reader.fill_buf() {
+            Ok(buffer) => buffer,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+        let (consumed, result) = parser.advance(buffer);
+        reader.consume(consumed);
+        if let Some(result) = result {
+            return result;
+        }
+    }
+}
+
+/// Skips leading whitespace and parses a single integer.
+pub(crate) struct IntParser<I> {
+    skip_whitespace: bool,
+    accumulator: DecimalAccumulator<I>,
+}
+
+impl<I> IntParser<I>
+where
+    I: FromRadix10SignedChecked,
+{
+    pub(crate) fn new() -> Self {
+        IntParser {
+            skip_whitespace: true,
+            accumulator: DecimalAccumulator::signed(),
+        }
+    }
+
+    fn finish(&mut self) -> io::Result<I> {
+        finish(mem::replace(
+            &mut self.accumulator,
+            DecimalAccumulator::signed(),
+        ))
+    }
+}
+
+impl<I> BufferedParser for IntParser<I>
+where
+    I: FromRadix10SignedChecked,
+{
+    type Output = I;
+
+    fn advance(&mut self, buffer: &[u8]) -> (usize, Option<io::Result<I>>) {
+        let mut consumed = 0;
+        if self.skip_whitespace {
+            consumed = leading_whitespace(buffer);
+            if consumed != 0 && consumed == buffer.len() {
+                return (consumed, None);
+            }
+            self.skip_whitespace = false;
+        }
+        // Overflows are reported by `finish`, once all digits of the number have been consumed.
+        match self.accumulator.feed(&buffer[consumed..]) {
+            Ok(Some(used)) => (consumed + used, Some(self.finish())),
+            // An empty buffer signals the end of the stream
+            Ok(None) | Err(_) if buffer.is_empty() => (0, Some(self.finish())),
+            Ok(None) | Err(_) => (buffer.len(), None),
+        }
+    }
+}
+
+/// Parses integers separated by whitespace until a delimiter.
+pub(crate) struct IntsUntilParser<I, F> {
+    delimiter: u8,
+    on_int: F,
+    /// The number currently parsed, `None` in between numbers.
+    number: Option<DecimalAccumulator<I>>,
+    count: usize,
+}
+
+impl<I, F> IntsUntilParser<I, F>
+where
+    I: FromRadix10SignedChecked,
+    F: FnMut(I),
+{
+    pub(crate) fn new(delimiter: u8, on_int: F) -> Self {
+        IntsUntilParser {
+            delimiter,
+            on_int,
+            number: None,
+            count: 0,
+        }
+    }
+
+    fn emit(&mut self, accumulator: DecimalAccumulator<I>) -> io::Result<()> {
+        (self.on_int)(finish(accumulator)?);
+        self.count += 1;
+        Ok(())
+    }
+}
+
+impl<I, F> BufferedParser for IntsUntilParser<I, F>
+where
+    I: FromRadix10SignedChecked,
+    F: FnMut(I),
+{
+    type Output = usize;
+
+    fn advance(&mut self, buffer: &[u8]) -> (usize, Option<io::Result<usize>>) {
+        if buffer.is_empty() {
+            let result = match self.number.take() {
+                Some(accumulator) => self.emit(accumulator).map(|()| self.count),
+                None => Ok(self.count),
+            };
+            return (0, Some(result));
+        }
+        let mut consumed = 0;
+        loop {
+            if let Some(accumulator) = &mut self.number {
+                match accumulator.feed(&buffer[consumed..]) {
+                    Ok(None) | Err(_) => return (buffer.len(), None),
+                    Ok(Some(used)) => consumed += used,
+                }
+            }
+            if let Some(accumulator) = self.number.take()
+                && let Err(error) = self.emit(accumulator)
+            {
+                return (consumed, Some(Err(error)));
+            }
+            consumed += buffer[consumed..]
+                .iter()
+                .take_while(|&&byte| byte != self.delimiter && is_whitespace(byte))
+                .count();
+            match buffer.get(consumed) {
+                None => return (consumed, None),
+                Some(&byte) if byte == self.delimiter => {
+                    return (consumed + 1, Some(Ok(self.count)));
+                }
+                Some(_) => self.number = Some(DecimalAccumulator::signed()),
+            }
+        }
+    }
+}
+
+fn finish<I>(accumulator: DecimalAccumulator<I>) -> io::Result<I> {
+    accumulator.finish().map_err(|error| {
+        let kind = match error {
+            ParseIntegerError::Empty => io::ErrorKind::UnexpectedEof,
+            _ => io::ErrorKind::InvalidData,
+        };
+        io::Error::new(kind, error)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufReader, ErrorKind};
+
+    use super::*;
+    use crate::Integer;
+
+    #[test]
+    fn read_integers_from_buf_read() {
+        // A tiny buffer forces numbers to span several refills
+        let mut reader = BufReader::with_capacity(3, &b"  -1234567 +89x 12 34\n\n5"[..]);
+        assert_eq!(-1234567, reader.read_int::<i32>().unwrap());
+        assert_eq!(89, reader.read_int::<i32>().unwrap());
+        let error = reader.read_int::<i32>().unwrap_err();
+        assert_eq!(ErrorKind::InvalidData, error.kind());
+        assert_eq!(
+            Some(&ParseIntegerError::InvalidDigit { position: 0 }),
+            error.get_ref().and_then(|error| error.downcast_ref())
+        );
+        reader.consume(1);
+        let mut ints: Vec<u8> = Vec::new();
+        assert_eq!(2, reader.read_ints_until(b'\n', |n| ints.push(n)).unwrap());
+        assert_eq!(0, reader.read_ints_until(b'\n', |n| ints.push(n)).unwrap());
+        assert_eq!(1, reader.read_ints_until(b'\n', |n| ints.push(n)).unwrap());
+        assert_eq!(vec![12u8, 34, 5], ints);
+
+        let mut reader = BufReader::with_capacity(2, &b"256"[..]);
+        let error = reader.read_int::<u8>().unwrap_err();
+        assert_eq!(ErrorKind::InvalidData, error.kind());
+
+        // Whitespace is the same as for `atoi_with_whitespace`, including the vertical tab
+        let mut reader = BufReader::with_capacity(2, &b"\x0B-7\x0B8\n"[..]);
+        assert_eq!(-7, reader.read_int::<Integer<i8>>().unwrap().0);
+        let mut ints: Vec<i8> = Vec::new();
+        assert_eq!(1, reader.read_ints_until(b'\n', |n| ints.push(n)).unwrap());
+        assert_eq!(vec![8], ints);
+    }
+}
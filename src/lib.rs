@@ -5,28 +5,75 @@
 //! is likely to be more idiomatic. Use this crate if you want to avoid decoding bytes into utf8
 //! (e.g. for performance reasons).
 //!
-//! Note that if you want to know how much of the input has been used, you can use the
-//! `FromRadix10` trait, for example:
+//! If you want to know how much of the input has been used, e.g. to parse several numbers in a
+//! row, use [`atoi_with_rest`] or the `*_with_rest` methods of the parsing traits. They return the
+//! bytes following the number, so calls can be chained without any index arithmetic:
 //!
 //! ```rust
-//! use atoi::FromRadix10;
+//! use atoi::atoi_with_rest;
 //!
-//! /// Return the parsed integer and remaining slice if successful.
-//! fn atoi_with_rest<I: FromRadix10>(text: &[u8]) -> ((&[u8], Option<I>)) {
-//!     match I::from_radix_10(text) {
-//!         (_, 0) => (text, None),
-//!         (n, used) => (&text[used..], Some(n)),
-//!     }
-//! }
+//! let (x, rest) = atoi_with_rest::<i32>(b"12,-34;");
+//! let rest = rest.strip_prefix(b",").unwrap();
+//! let (y, rest) = atoi_with_rest::<i32>(rest);
+//! assert_eq!((Some(12), Some(-34), &b";"[..]), (x, y, rest));
 //! ```
+//!
+//! [`atoi_with_rest_str`] does the same for a `str` and returns the rest as a `str`.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use core::cmp::max;
+
 use num_traits::Signed;
 
+mod accumulator;
+#[cfg(any(feature = "tokio", feature = "futures-io"))]
+mod async_io;
+#[cfg(feature = "bytes")]
+mod buf;
 mod builtin;
+mod error;
+mod exact;
+mod float;
+mod format;
 mod integer;
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "nom")]
+pub mod nom;
+mod numbers;
+mod options;
+mod prefix;
+mod row;
+mod separator;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod swar;
+mod whitespace;
 
+pub use accumulator::{Accumulator, DecimalAccumulator, HexAccumulator};
+#[cfg(feature = "futures-io")]
+pub use async_io::futures_io;
+#[cfg(feature = "tokio")]
+pub use async_io::tokio;
+#[cfg(feature = "bytes")]
+pub use buf::BufIntegerExt;
+pub use error::ParseIntegerError;
+pub use exact::{
+    from_fixed_width_radix_10, parse_exact, parse_exact_radix_10, parse_exact_radix_16,
+    parse_exact_radix_16_signed,
+};
 pub use integer::Integer;
+#[cfg(feature = "std")]
+pub use io::BufReadIntegerExt;
+pub use numbers::{Numbers, numbers};
+pub use options::{FromParseOptions, OverflowPolicy, ParseOptions, TrailingPolicy, parse};
+pub use prefix::{PrefixStyle, atoi_auto};
+pub use row::{FieldError, FromRow, parse_row, parse_row_into};
+pub use separator::{
+    Separator, from_radix_10_separated, from_radix_10_signed_separated, from_radix_16_separated,
+    from_radix_16_signed_separated,
+};
+pub use whitespace::{Whitespace, atoi_with_whitespace, try_atoi_with_whitespace};
 
 /// Parses an integer from a slice.
 ///
@@ -43,8 +90,8 @@ pub use integer::Integer;
 /// use atoi::atoi;
 /// // Parsing to digits from a slice
 /// assert_eq!(Some(42), atoi::<u32>(b"42"));
-/// // Additional bytes after the number are ignored. If you want to know how many bytes were used
-/// // to parse the number use `FromRadix10::from_radix_10`.
+/// // Additional bytes after the number are ignored. If you want to know where the number ends use
+/// // `atoi_with_rest`. Use `parse_exact` to reject them.
 /// assert_eq!(Some(42), atoi::<u32>(b"42 is the answer to life, the universe and everything"));
 /// // `None` is returned if the slice does not start with a digit
 /// assert_eq!(None, atoi::<u32>(b"Sadly we do not know the question"));
@@ -56,22 +103,151 @@ pub use integer::Integer;
 /// assert_eq!(Some(42), atoi::<u32>(b"0042"));
 /// // Overflows will return `None`
 /// assert_eq!(None, atoi::<u8>(b"256"));
+/// // Anything which can be viewed as a slice of bytes is accepted, e.g. `str`
+/// assert_eq!(Some(42), atoi::<u32>("42"));
 /// ```
 ///
 /// # Return
 ///
-/// Returns a a number if the slice started with a number, otherwise `None` is returned.
+/// Returns a a number if the slice started with a number, otherwise `None` is returned. A sign
+/// which is not followed by any digit is parsed as zero. Use [`try_atoi`] to reject it and to
+/// learn why no number could be parsed. Leading whitespace is not skipped, use
+/// [`atoi_with_whitespace`] or [`ParseOptions::skip_leading_whitespace`] for that.
 #[inline]
-pub fn atoi<I>(text: &[u8]) -> Option<I>
+pub fn atoi<I>(text: impl AsRef<[u8]>) -> Option<I>
 where
     I: FromRadix10SignedChecked,
 {
-    match I::from_radix_10_signed_checked(text) {
+    match I::from_radix_10_signed_checked(text.as_ref()) {
         (_, 0) | (None, _) => None,
         (Some(n), _) => Some(n),
     }
 }
 
+/// Parses an integer from a slice and reports why parsing failed.
+///
+/// Acts like [`atoi`], but in addition to the integer it returns the index of the byte right after
+/// the parsed number. In case of an error it tells apart an empty slice, a missing digit, a sign
+/// without digits and overflows.
+///
+/// # Example
+///
+/// ```
+/// use atoi::{try_atoi, ParseIntegerError};
+/// assert_eq!(Ok((42, 2)), try_atoi::<u32>(b"42 is the answer"));
+/// assert_eq!(Ok((-42, 3)), try_atoi::<i32>(b"-42"));
+/// assert_eq!(Err(ParseIntegerError::Empty), try_atoi::<u32>(b""));
+/// assert_eq!(Err(ParseIntegerError::InvalidDigit { position: 0 }), try_atoi::<u32>(b"Sadly"));
+/// assert_eq!(Err(ParseIntegerError::InvalidDigit { position: 1 }), try_atoi::<i32>(b"-x"));
+/// assert_eq!(Err(ParseIntegerError::SignOnly), try_atoi::<i32>(b"-"));
+/// assert_eq!(Err(ParseIntegerError::PosOverflow), try_atoi::<u8>(b"256"));
+/// assert_eq!(Err(ParseIntegerError::NegOverflow), try_atoi::<u8>(b"-1"));
+/// ```
+#[inline]
+pub fn try_atoi<I>(text: impl AsRef<[u8]>) -> Result<(I, usize), ParseIntegerError>
+where
+    I: FromRadix10SignedChecked,
+{
+    I::try_from_radix_10_signed_checked(text.as_ref())
+}
+
+/// Parses an integer from a slice and returns it together with the bytes following it.
+///
+/// Acts like [`atoi`], but also tells where the number ends. This is the preferred way to parse
+/// several numbers in a row. The parsing traits offer the same with their `*_with_rest` methods,
+/// e.g. [`FromRadix10Checked::from_radix_10_checked_with_rest`].
+///
+/// # Example
+///
+/// ```
+/// use atoi::atoi_with_rest;
+/// assert_eq!((Some(42), &b" is the answer"[..]), atoi_with_rest::<u32>(b"42 is the answer"));
+/// assert_eq!((Some(-42), &b""[..]), atoi_with_rest::<i32>("-42"));
+/// // If there is no number, all of the input is returned
+/// assert_eq!((None, &b"x"[..]), atoi_with_rest::<i32>(b"x"));
+/// // A sign without digits is parsed as zero, like by `atoi`
+/// assert_eq!((Some(0), &b"x"[..]), atoi_with_rest::<i32>(b"-x"));
+/// // Overflows will return `None`, yet the digits are consumed
+/// assert_eq!((None, &b" x"[..]), atoi_with_rest::<u8>(b"256 x"));
+/// ```
+///
+/// # Return
+///
+/// The parsed integer, or `None` if the slice does not start with a number or the number
+/// overflowed. The bytes following the number, or all of `text` if there is no number.
+#[inline]
+pub fn atoi_with_rest<I>(text: &(impl AsRef<[u8]> + ?Sized)) -> (Option<I>, &[u8])
+where
+    I: FromRadix10SignedChecked,
+{
+    I::from_radix_10_signed_checked_with_rest(text.as_ref())
+}
+
+/// Parses an integer from a string and returns it together with the remainder of the string.
+///
+/// Acts like [`atoi_with_rest`], but keeps the rest a `str`, so calls can be chained without
+/// converting between bytes and strings.
+///
+/// # Example
+///
+/// ```
+/// use atoi::atoi_with_rest_str;
+/// let (x, rest) = atoi_with_rest_str::<i32>("12,-34 äpfel");
+/// let (y, rest) = atoi_with_rest_str::<i32>(rest.strip_prefix(',').unwrap());
+/// assert_eq!((Some(12), Some(-34), " äpfel"), (x, y, rest));
+/// // If there is no number, all of the input is returned
+/// assert_eq!((None, "äpfel"), atoi_with_rest_str::<i32>("äpfel"));
+/// ```
+///
+/// # Return
+///
+/// The parsed integer, or `None` if the string does not start with a number or the number
+/// overflowed. The remainder of the string following the number, or all of `text` if there is no
+/// number.
+#[inline]
+pub fn atoi_with_rest_str<I>(text: &str) -> (Option<I>, &str)
+where
+    I: FromRadix10SignedChecked,
+{
+    let (number, rest) = I::from_radix_10_signed_checked_with_rest(text.as_bytes());
+    // Sign and digits are ASCII, so the rest always starts at a char boundary.
+    (number, &text[text.len() - rest.len()..])
+}
+
+/// Parses a floating point number from a slice.
+///
+/// Accepts an optional sign, followed by decimal digits with an optional decimal point and an
+/// optional exponent. `inf`, `infinity` and `nan` are accepted regardless of their case. The result
+/// is rounded to the nearest representable value.
+///
+/// # Example
+///
+/// ```
+/// use atoi::atof;
+/// assert_eq!(Some(42.5), atof::<f64>(b"42.5"));
+/// // Additional bytes after the number are ignored. If you want to know how many bytes were used
+/// // to parse the number use `FromRadix10Float::from_radix_10_float`.
+/// assert_eq!(Some(-0.25), atof::<f32>(b"-2.5e-1 is a quarter"));
+/// assert_eq!(Some(f64::INFINITY), atof::<f64>(b"+Infinity"));
+/// // `None` is returned if the slice does not start with a number
+/// assert_eq!(None, atof::<f64>(b"Sadly we do not know the question"));
+/// assert_eq!(None, atof::<f64>(b"."));
+/// ```
+///
+/// # Return
+///
+/// Returns a a number if the slice started with a number, otherwise `None` is returned.
+#[inline]
+pub fn atof<F>(text: impl AsRef<[u8]>) -> Option<F>
+where
+    F: FromRadix10Float,
+{
+    match F::from_radix_10_float(text.as_ref()) {
+        (_, 0) => None,
+        (n, _) => Some(n),
+    }
+}
+
 /// Types implementing this trait can be parsed from a positional numeral system with radix 10
 pub trait FromRadix10: Sized {
     /// Parses an integer from a slice.
@@ -100,6 +276,29 @@ pub trait FromRadix10: Sized {
     /// index of the byte right after the parsed number. If the second element is zero the slice
     /// did not start with an ASCII digit.
     fn from_radix_10(_: &[u8]) -> (Self, usize);
+
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_radix_10`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadix10;
+    /// assert_eq!(
+    ///     (Some(42), &b" is the answer"[..]),
+    ///     u32::from_radix_10_with_rest(b"42 is the answer")
+    /// );
+    /// assert_eq!((None, &b"x"[..]), u32::from_radix_10_with_rest(b"x"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed number, or `None` if the slice does not start with a number. The bytes
+    /// following the number, or all of `text` if there is no number.
+    fn from_radix_10_with_rest(text: &[u8]) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_radix_10(text);
+        split_rest(text, Some(number), used)
+    }
 }
 
 /// Types implementing this trait can be parsed from a positional numeral system with radix 10.
@@ -134,6 +333,111 @@ pub trait FromRadix10Checked: Sized {
     /// The second is the index of the byte right after the parsed number. If the second element is
     /// zero the slice did not start with an ASCII digit.
     fn from_radix_10_checked(_: &[u8]) -> (Option<Self>, usize);
+
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_radix_10_checked`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadix10Checked;
+    /// assert_eq!(
+    ///     (Some(42), &b" is the answer"[..]),
+    ///     u32::from_radix_10_checked_with_rest(b"42 is the answer")
+    /// );
+    /// assert_eq!((None, &b"-42"[..]), u32::from_radix_10_checked_with_rest(b"-42"));
+    /// // Overflow is indicated by `None`, but the digits are still consumed
+    /// assert_eq!((None, &b" x"[..]), u8::from_radix_10_checked_with_rest(b"256 x"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed integer, or `None` if the slice does not start with a number or the number
+    /// overflowed. The bytes following the number, or all of `text` if there is no number.
+    fn from_radix_10_checked_with_rest(text: &[u8]) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_radix_10_checked(text);
+        split_rest(text, number, used)
+    }
+
+    /// Parses an integer from a slice. Acts like [`Self::from_radix_10_checked`], but reports why
+    /// parsing failed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::{FromRadix10Checked, ParseIntegerError};
+    /// assert_eq!(Ok((42, 2)), u32::try_from_radix_10_checked(b"42"));
+    /// assert_eq!(Err(ParseIntegerError::Empty), u32::try_from_radix_10_checked(b""));
+    /// assert_eq!(
+    ///     Err(ParseIntegerError::InvalidDigit { position: 0 }),
+    ///     u32::try_from_radix_10_checked(b"-42")
+    /// );
+    /// assert_eq!(Err(ParseIntegerError::PosOverflow), u8::try_from_radix_10_checked(b"256"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns the parsed integer and the index of the byte right after the parsed number.
+    fn try_from_radix_10_checked(text: &[u8]) -> Result<(Self, usize), ParseIntegerError> {
+        error::unsigned_result(text, Self::from_radix_10_checked(text))
+    }
+
+    /// Parses an integer from a fixed width field of `N` bytes, which may be padded with leading
+    /// spaces or zeros. Acts like [`from_fixed_width_radix_10`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::{FromRadix10Checked, ParseIntegerError};
+    /// assert_eq!(Ok(42), u32::try_from_fixed_width_radix_10_checked(b"  0042"));
+    /// assert_eq!(
+    ///     Err(ParseIntegerError::InvalidDigit { position: 1 }),
+    ///     u32::try_from_fixed_width_radix_10_checked(b" +42")
+    /// );
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns the parsed integer.
+    fn try_from_fixed_width_radix_10_checked<const N: usize>(
+        field: &[u8; N],
+    ) -> Result<Self, ParseIntegerError> {
+        exact::fixed_width_radix_10(field)
+    }
+
+    /// Parses an integer from a slice, whose digits may be split by `separator`. Acts like
+    /// [`Self::from_radix_10_checked`], but the number of bytes used includes the separators. A
+    /// separator is only consumed if it is followed by a digit and meets the grouping requirements
+    /// of `separator`.
+    ///
+    /// Numbers without separators are parsed by [`Self::from_radix_10_checked`] directly.
+    /// Otherwise the digits are parsed after removing the separators, in which case numbers with
+    /// more than 128 significant digits are reported as overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::{FromRadix10Checked, Separator};
+    /// let thousands = Separator::new(',').grouped(3);
+    /// assert_eq!(
+    ///     (Some(1_234_567), 9),
+    ///     u32::from_radix_10_checked_separated(b"1,234,567", thousands)
+    /// );
+    /// // Parsing stops in front of a separator violating the grouping
+    /// assert_eq!(
+    ///     (Some(1_234), 5),
+    ///     u32::from_radix_10_checked_separated(b"1,234,56", thousands)
+    /// );
+    /// assert_eq!((None, 5), u8::from_radix_10_checked_separated(b"1,000", thousands));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns a tuple with two numbers. The first is the integer parsed, or `None` in case of
+    /// overflow. The second is the index of the byte right after the parsed number.
+    fn from_radix_10_checked_separated(text: &[u8], separator: Separator) -> (Option<Self>, usize) {
+        separator::parse_separated(text, 10, false, separator, Self::from_radix_10_checked)
+    }
 }
 
 /// Types implementing this trait can be parsed from a positional numeral system with radix 16
@@ -166,6 +470,29 @@ pub trait FromRadix16: Sized {
     /// index of the byte right after the parsed number. If the second element is zero the slice
     /// did not start with an ASCII digit.
     fn from_radix_16(_: &[u8]) -> (Self, usize);
+
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_radix_16`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadix16;
+    /// assert_eq!(
+    ///     (Some(42), &b";"[..]),
+    ///     u32::from_radix_16_with_rest(b"2a;")
+    /// );
+    /// assert_eq!((None, &b"x"[..]), u32::from_radix_16_with_rest(b"x"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed number, or `None` if the slice does not start with a number. The bytes
+    /// following the number, or all of `text` if there is no number.
+    fn from_radix_16_with_rest(text: &[u8]) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_radix_16(text);
+        split_rest(text, Some(number), used)
+    }
 }
 
 /// Types implementing this trait can be parsed from a positional numeral system with radix 16.
@@ -201,6 +528,75 @@ pub trait FromRadix16Checked: Sized {
     /// The second is the index of the byte right after the parsed number. If the second element is
     /// zero the slice did not start with an ASCII digit.
     fn from_radix_16_checked(_: &[u8]) -> (Option<Self>, usize);
+
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_radix_16_checked`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadix16Checked;
+    /// assert_eq!(
+    ///     (Some(42), &b";"[..]),
+    ///     u32::from_radix_16_checked_with_rest(b"2a;")
+    /// );
+    /// assert_eq!((None, &b"x"[..]), u32::from_radix_16_checked_with_rest(b"x"));
+    /// assert_eq!((None, &b";"[..]), u8::from_radix_16_checked_with_rest(b"100;"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed integer, or `None` if the slice does not start with a number or the number
+    /// overflowed. The bytes following the number, or all of `text` if there is no number.
+    fn from_radix_16_checked_with_rest(text: &[u8]) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_radix_16_checked(text);
+        split_rest(text, number, used)
+    }
+
+    /// Parses an integer from a slice. Acts like [`Self::from_radix_16_checked`], but reports why
+    /// parsing failed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::{FromRadix16Checked, ParseIntegerError};
+    /// assert_eq!(Ok((42, 2)), u32::try_from_radix_16_checked(b"2a"));
+    /// assert_eq!(Err(ParseIntegerError::Empty), u32::try_from_radix_16_checked(b""));
+    /// assert_eq!(
+    ///     Err(ParseIntegerError::InvalidDigit { position: 0 }),
+    ///     u32::try_from_radix_16_checked(b"x2a")
+    /// );
+    /// assert_eq!(Err(ParseIntegerError::PosOverflow), u8::try_from_radix_16_checked(b"100"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns the parsed integer and the index of the byte right after the parsed number.
+    fn try_from_radix_16_checked(text: &[u8]) -> Result<(Self, usize), ParseIntegerError> {
+        error::unsigned_result(text, Self::from_radix_16_checked(text))
+    }
+
+    /// Parses an integer from a slice, whose digits may be split by `separator`. Acts like
+    /// [`FromRadix10Checked::from_radix_10_checked_separated`], but for hexadecimal digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::{FromRadix16Checked, Separator};
+    /// let words = Separator::new('_').grouped(4);
+    /// assert_eq!(
+    ///     (Some(0xdead_beef), 9),
+    ///     u32::from_radix_16_checked_separated(b"dead_beef", words)
+    /// );
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns a tuple with two numbers. The first is the integer parsed, or `None` in case of
+    /// overflow. The second is the index of the byte right after the parsed number.
+    fn from_radix_16_checked_separated(text: &[u8], separator: Separator) -> (Option<Self>, usize) {
+        separator::parse_separated(text, 16, false, separator, Self::from_radix_16_checked)
+    }
 }
 
 /// Types implementing this trait can be parsed from a positional numeral system with radix 10. This
@@ -235,6 +631,29 @@ pub trait FromRadix10Signed: Sized {
     /// index of the byte right after the parsed number. If the second element is zero the slice
     /// did not start with an ASCII digit.
     fn from_radix_10_signed(_: &[u8]) -> (Self, usize);
+
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_radix_10_signed`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadix10Signed;
+    /// assert_eq!(
+    ///     (Some(-42), &b",1"[..]),
+    ///     i32::from_radix_10_signed_with_rest(b"-42,1")
+    /// );
+    /// assert_eq!((Some(0), &b"x"[..]), i32::from_radix_10_signed_with_rest(b"-x"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed number, or `None` if the slice does not start with a number. The bytes
+    /// following the number, or all of `text` if there is no number.
+    fn from_radix_10_signed_with_rest(text: &[u8]) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_radix_10_signed(text);
+        split_rest(text, Some(number), used)
+    }
 }
 
 /// Types implementing this trait can be parsed from a positional numeral system with radix 10.
@@ -277,78 +696,1238 @@ pub trait FromRadix10SignedChecked: FromRadix10Signed {
     /// The second is the index of the byte right after the parsed number. If the second element is
     /// zero the slice did not start with an ASCII digit.
     fn from_radix_10_signed_checked(_: &[u8]) -> (Option<Self>, usize);
-}
-
-/// A bounded integer, whose representation can overflow and therefore can only store a maximum
-/// number of digits
-pub trait MaxNumDigits {
-    /// Given a representation with a radix character I, what is the maximum number of digits we can
-    /// parse without the integer overflowing for sure?
-    fn max_num_digits(radix: Self) -> usize;
-
-    /// Returns the maximum number of digits a negative representation of `I` can have depending on
-    /// `radix`.
-    fn max_num_digits_negative(radix: Self) -> usize;
-}
 
-/// Representation of a numerical sign
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum Sign {
-    Plus,
-    Minus,
-}
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_radix_10_signed_checked`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadix10SignedChecked;
+    /// assert_eq!(
+    ///     (Some(-42), &b",1"[..]),
+    ///     i32::from_radix_10_signed_checked_with_rest(b"-42,1")
+    /// );
+    /// assert_eq!((Some(0), &b"x"[..]), i32::from_radix_10_signed_checked_with_rest(b"-x"));
+    /// assert_eq!((None, &b",1"[..]), i8::from_radix_10_signed_checked_with_rest(b"-129,1"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed integer, or `None` if the slice does not start with a number or the number
+    /// overflowed. The bytes following the number, or all of `text` if there is no number.
+    fn from_radix_10_signed_checked_with_rest(text: &[u8]) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_radix_10_signed_checked(text);
+        split_rest(text, number, used)
+    }
 
-impl Sign {
-    /// Trys to convert an ascii character into a `Sign`
+    /// Parses an integer from a slice. Acts like [`Self::from_radix_10_signed_checked`], but
+    /// reports why parsing failed.
     ///
     /// # Example
     ///
     /// ```
-    /// use atoi::Sign;
-    /// assert_eq!(Some(Sign::Plus), Sign::try_from(b'+'));
-    /// assert_eq!(Some(Sign::Minus), Sign::try_from(b'-'));
-    /// assert_eq!(None, Sign::try_from(b'1'));
+    /// use atoi::{FromRadix10SignedChecked, ParseIntegerError};
+    /// assert_eq!(Ok((-42, 3)), i32::try_from_radix_10_signed_checked(b"-42"));
+    /// assert_eq!(Err(ParseIntegerError::Empty), i32::try_from_radix_10_signed_checked(b""));
+    /// assert_eq!(Err(ParseIntegerError::SignOnly), i32::try_from_radix_10_signed_checked(b"+"));
+    /// assert_eq!(
+    ///     Err(ParseIntegerError::InvalidDigit { position: 1 }),
+    ///     i32::try_from_radix_10_signed_checked(b"+-42")
+    /// );
+    /// assert_eq!(
+    ///     Err(ParseIntegerError::PosOverflow),
+    ///     i8::try_from_radix_10_signed_checked(b"128")
+    /// );
+    /// assert_eq!(
+    ///     Err(ParseIntegerError::NegOverflow),
+    ///     i8::try_from_radix_10_signed_checked(b"-129")
+    /// );
     /// ```
-    pub fn try_from(byte: u8) -> Option<Sign> {
-        match byte {
-            b'+' => Some(Sign::Plus),
-            b'-' => Some(Sign::Minus),
-            _ => None,
-        }
+    ///
+    /// # Return
+    ///
+    /// Returns the parsed integer and the index of the byte right after the parsed number.
+    fn try_from_radix_10_signed_checked(text: &[u8]) -> Result<(Self, usize), ParseIntegerError> {
+        error::signed_result(text, Self::from_radix_10_signed_checked(text))
     }
 
-    /// Returns either `+1` or `-1`
-    pub fn signum<I>(self) -> I
-    where
-        I: Signed,
-    {
-        match self {
-            Sign::Plus => I::one(),
-            Sign::Minus => -I::one(),
-        }
+    /// Parses an integer with an optional leading sign from a slice, whose digits may be split by
+    /// `separator`. Acts like [`FromRadix10Checked::from_radix_10_checked_separated`], but allows
+    /// for a sign.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::{FromRadix10SignedChecked, Separator};
+    /// let thousands = Separator::new(',').grouped(3);
+    /// assert_eq!(
+    ///     (Some(-1_234_567), 10),
+    ///     i32::from_radix_10_signed_checked_separated(b"-1,234,567", thousands)
+    /// );
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns a tuple with two numbers. The first is the integer parsed, or `None` in case of
+    /// overflow. The second is the index of the byte right after the parsed number.
+    fn from_radix_10_signed_checked_separated(
+        text: &[u8],
+        separator: Separator,
+    ) -> (Option<Self>, usize) {
+        separator::parse_separated(
+            text,
+            10,
+            true,
+            separator,
+            Self::from_radix_10_signed_checked,
+        )
     }
 }
 
-/// Construct an instance of a numerical type using the byte representation of a radix 10 digit,
-/// e.g. b'7' -> 7.
-pub trait FromDigit: Sized {
-    /// Convert ASCII digit (e.g. b'7) into numeric representation (`7`). `None` if the character
-    /// given does not represent a digit in ASCII.
+/// Types implementing this trait can be parsed from a positional numeral system with radix 16. This
+/// trait allows for an additional sign character (`+` or `-`) in front of the actual number in
+/// order, to allow for parsing negative values.
+pub trait FromRadix16Signed: Sized {
+    /// Parses an integer from a slice.
     ///
     /// # Example
     ///
     /// ```
-    /// use atoi::FromDigit;
-    /// assert_eq!(Some(5), u32::from_digit(b'5'));
-    /// assert_eq!(None, u32::from_digit(b'x'));
+    /// use atoi::FromRadix16Signed;
+    /// // Parsing to digits from a slice
+    /// assert_eq!((42,2), i32::from_radix_16_signed(b"2a"));
+    /// // Additional bytes after the number are ignored
+    /// assert_eq!((42,2), i32::from_radix_16_signed(b"2a is the answer to life, the universe and everything"));
+    /// // (0,0) is returned if the slice does not start with a digit
+    /// assert_eq!((0,0), i32::from_radix_16_signed(b"Sadly we do not know the question"));
+    /// // Signs are allowed
+    /// assert_eq!((-31,3), i32::from_radix_16_signed(b"-1F"));
+    /// // Signs are allowed
+    /// assert_eq!((31,3), i32::from_radix_16_signed(b"+1f"));
+    /// // Even on unsigned types.
+    /// assert_eq!((0,2), u32::from_radix_16_signed(b"-0"));
+    /// // Leading zeros are allowed
+    /// assert_eq!((42,4), i32::from_radix_16_signed(b"002a"));
     /// ```
-    fn from_digit(digit: u8) -> Option<Self>;
-}
+    ///
+    /// # Return
+    ///
+    /// Returns a tuple with two numbers. The first is the integer parsed or zero, the second is the
+    /// index of the byte right after the parsed number. If the second element is zero the slice
+    /// did not start with an ASCII digit.
+    fn from_radix_16_signed(_: &[u8]) -> (Self, usize);
+
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_radix_16_signed`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadix16Signed;
+    /// assert_eq!(
+    ///     (Some(-42), &b",1"[..]),
+    ///     i32::from_radix_16_signed_with_rest(b"-2a,1")
+    /// );
+    /// assert_eq!((Some(0), &b""[..]), i32::from_radix_16_signed_with_rest(b"+"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed number, or `None` if the slice does not start with a number. The bytes
+    /// following the number, or all of `text` if there is no number.
+    fn from_radix_16_signed_with_rest(text: &[u8]) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_radix_16_signed(text);
+        split_rest(text, Some(number), used)
+    }
+}
+
+/// Types implementing this trait can be parsed from a positional numeral system with radix 16.
+/// Acts much like [`FromRadix16Signed`], but performs additional checks for overflows.
+pub trait FromRadix16SignedChecked: FromRadix16Signed {
+    /// Parses an integer from a slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadix16SignedChecked;
+    /// // Parsing to digits from a slice
+    /// assert_eq!((Some(42),2), u32::from_radix_16_signed_checked(b"2a"));
+    /// // (0,0) is returned if the slice does not start with a digit
+    /// assert_eq!((Some(0),0), u32::from_radix_16_signed_checked(b"Sadly we do not know the question"));
+    /// // Signs are allowed
+    /// assert_eq!((Some(-31),3), i32::from_radix_16_signed_checked(b"-1F"));
+    /// // -0 is ok, even for an unsigned type
+    /// assert_eq!((Some(0),2), u32::from_radix_16_signed_checked(b"-0"));
+    /// // Negative values for unsigned types are handled as `None`.
+    /// assert_eq!((None,3), u32::from_radix_16_signed_checked(b"-1F"));
+    /// // Overflow is indicated by `None`
+    /// assert_eq!((None, 3), u8::from_radix_16_signed_checked(b"100"));
+    /// assert_eq!((None, 3), i8::from_radix_16_signed_checked(b"+80"));
+    /// assert_eq!((Some(-128), 3), i8::from_radix_16_signed_checked(b"-80"));
+    /// assert_eq!((None, 3), i8::from_radix_16_signed_checked(b"-81"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns a tuple with two numbers. The first is the integer parsed or zero if no digit has
+    /// been found. None, if there were too many, or too high dighits and the parsing overflowed.
+    /// The second is the index of the byte right after the parsed number. If the second element is
+    /// zero the slice did not start with an ASCII digit.
+    fn from_radix_16_signed_checked(_: &[u8]) -> (Option<Self>, usize);
+
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_radix_16_signed_checked`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadix16SignedChecked;
+    /// assert_eq!(
+    ///     (Some(-42), &b",1"[..]),
+    ///     i32::from_radix_16_signed_checked_with_rest(b"-2a,1")
+    /// );
+    /// assert_eq!((Some(0), &b""[..]), i32::from_radix_16_signed_checked_with_rest(b"+"));
+    /// assert_eq!((None, &b",1"[..]), i8::from_radix_16_signed_checked_with_rest(b"-81,1"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed integer, or `None` if the slice does not start with a number or the number
+    /// overflowed. The bytes following the number, or all of `text` if there is no number.
+    fn from_radix_16_signed_checked_with_rest(text: &[u8]) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_radix_16_signed_checked(text);
+        split_rest(text, number, used)
+    }
+
+    /// Parses an integer from a slice. Acts like [`Self::from_radix_16_signed_checked`], but
+    /// reports why parsing failed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::{FromRadix16SignedChecked, ParseIntegerError};
+    /// assert_eq!(Ok((-31, 3)), i32::try_from_radix_16_signed_checked(b"-1f"));
+    /// assert_eq!(Err(ParseIntegerError::SignOnly), i32::try_from_radix_16_signed_checked(b"-"));
+    /// assert_eq!(
+    ///     Err(ParseIntegerError::NegOverflow),
+    ///     u32::try_from_radix_16_signed_checked(b"-1f")
+    /// );
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns the parsed integer and the index of the byte right after the parsed number.
+    fn try_from_radix_16_signed_checked(text: &[u8]) -> Result<(Self, usize), ParseIntegerError> {
+        error::signed_result(text, Self::from_radix_16_signed_checked(text))
+    }
+
+    /// Parses an integer with an optional leading sign from a slice, whose digits may be split by
+    /// `separator`. Acts like [`FromRadix10Checked::from_radix_10_checked_separated`], but for
+    /// hexadecimal digits and allows for a sign.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::{FromRadix16SignedChecked, Separator};
+    /// assert_eq!(
+    ///     (Some(-0x7f_ff), 6),
+    ///     i32::from_radix_16_signed_checked_separated(b"-7f_ff", Separator::new('_'))
+    /// );
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns a tuple with two numbers. The first is the integer parsed, or `None` in case of
+    /// overflow. The second is the index of the byte right after the parsed number.
+    fn from_radix_16_signed_checked_separated(
+        text: &[u8],
+        separator: Separator,
+    ) -> (Option<Self>, usize) {
+        separator::parse_separated(
+            text,
+            16,
+            true,
+            separator,
+            Self::from_radix_16_signed_checked,
+        )
+    }
+}
+
+/// Types implementing this trait can be parsed from a positional numeral system with a radix
+/// specified at runtime. Digits above `9` are represented by the letters `a` to `z`, regardless of
+/// their case.
+pub trait FromRadix: Sized {
+    /// Parses an integer from a slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadix;
+    /// // Parsing octal digits from a slice
+    /// assert_eq!((493,3), u32::from_radix(b"755", 8));
+    /// // Additional bytes after the number are ignored
+    /// assert_eq!((5,3), u32::from_radix(b"101 is five in binary", 2));
+    /// // (0,0) is returned if the slice does not start with a digit of the radix
+    /// assert_eq!((0,0), u32::from_radix(b"9", 8));
+    /// // Signs are not allowed (even for signed integer types)
+    /// assert_eq!((0,0), i32::from_radix(b"-755", 8));
+    /// // Letters are allowed for radices above 10, regardless of their case
+    /// assert_eq!((1295,2), u32::from_radix(b"zZ", 36));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns a tuple with two numbers. The first is the integer parsed or zero, the second is the
+    /// index of the byte right after the parsed number. If the second element is zero the slice
+    /// did not start with a digit of the radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    fn from_radix(_: &[u8], radix: u32) -> (Self, usize);
+
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_radix`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadix;
+    /// assert_eq!(
+    ///     (Some(493), &b" rwx"[..]),
+    ///     u32::from_radix_with_rest(b"755 rwx", 8)
+    /// );
+    /// assert_eq!((None, &b"9"[..]), u32::from_radix_with_rest(b"9", 8));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed number, or `None` if the slice does not start with a number. The bytes
+    /// following the number, or all of `text` if there is no number.
+    fn from_radix_with_rest(text: &[u8], radix: u32) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_radix(text, radix);
+        split_rest(text, Some(number), used)
+    }
+}
+
+/// Types implementing this trait can be parsed from a positional numeral system with a radix
+/// specified at runtime. Acts much like [`FromRadix`], but performs additional checks for
+/// overflows.
+pub trait FromRadixChecked: Sized {
+    /// Parses an integer from a slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadixChecked;
+    /// // Parsing binary digits from a slice
+    /// assert_eq!((Some(10),4), u8::from_radix_checked(b"1010", 2));
+    /// // Additional bytes after the number are ignored
+    /// assert_eq!((Some(10),4), u8::from_radix_checked(b"1010 is ten", 2));
+    /// // (0,0) is returned if the slice does not start with a digit of the radix
+    /// assert_eq!((Some(0),0), u8::from_radix_checked(b"2", 2));
+    /// // Overflow is indicated by `None`
+    /// assert_eq!((None, 3), u8::from_radix_checked(b"400", 8));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns a tuple with two numbers. The first is the integer parsed or zero if no digit has
+    /// been found. None, if there were too many, or too high dighits and the parsing overflowed.
+    /// The second is the index of the byte right after the parsed number. If the second element is
+    /// zero the slice did not start with a digit of the radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    fn from_radix_checked(_: &[u8], radix: u32) -> (Option<Self>, usize);
+
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_radix_checked`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadixChecked;
+    /// assert_eq!(
+    ///     (Some(493), &b" rwx"[..]),
+    ///     u32::from_radix_checked_with_rest(b"755 rwx", 8)
+    /// );
+    /// assert_eq!((None, &b"9"[..]), u32::from_radix_checked_with_rest(b"9", 8));
+    /// assert_eq!((None, &b" rwx"[..]), u8::from_radix_checked_with_rest(b"400 rwx", 8));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed integer, or `None` if the slice does not start with a number or the number
+    /// overflowed. The bytes following the number, or all of `text` if there is no number.
+    fn from_radix_checked_with_rest(text: &[u8], radix: u32) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_radix_checked(text, radix);
+        split_rest(text, number, used)
+    }
+}
+
+/// Types implementing this trait can be parsed from a positional numeral system with a radix
+/// specified at runtime. This trait allows for an additional sign character (`+` or `-`) in front
+/// of the actual number in order, to allow for parsing negative values.
+pub trait FromRadixSigned: Sized {
+    /// Parses an integer from a slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadixSigned;
+    /// // Parsing octal digits from a slice
+    /// assert_eq!((493,3), i32::from_radix_signed(b"755", 8));
+    /// // Signs are allowed
+    /// assert_eq!((-493,4), i32::from_radix_signed(b"-755", 8));
+    /// assert_eq!((493,4), i32::from_radix_signed(b"+755", 8));
+    /// // (0,0) is returned if the slice does not start with a digit of the radix
+    /// assert_eq!((0,0), i32::from_radix_signed(b"Sadly we do not know the question", 8));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns a tuple with two numbers. The first is the integer parsed or zero, the second is the
+    /// index of the byte right after the parsed number. If the second element is zero the slice
+    /// did not start with a digit of the radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    fn from_radix_signed(_: &[u8], radix: u32) -> (Self, usize);
+
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_radix_signed`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadixSigned;
+    /// assert_eq!(
+    ///     (Some(-5), &b"b"[..]),
+    ///     i32::from_radix_signed_with_rest(b"-101b", 2)
+    /// );
+    /// assert_eq!((Some(0), &b"2"[..]), i32::from_radix_signed_with_rest(b"-2", 2));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed number, or `None` if the slice does not start with a number. The bytes
+    /// following the number, or all of `text` if there is no number.
+    fn from_radix_signed_with_rest(text: &[u8], radix: u32) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_radix_signed(text, radix);
+        split_rest(text, Some(number), used)
+    }
+}
+
+/// Types implementing this trait can be parsed from a positional numeral system with a radix
+/// specified at runtime. Acts much like [`FromRadixSigned`], but performs additional checks for
+/// overflows.
+pub trait FromRadixSignedChecked: FromRadixSigned {
+    /// Parses an integer from a slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadixSignedChecked;
+    /// // Parsing octal digits from a slice
+    /// assert_eq!((Some(-128),4), i8::from_radix_signed_checked(b"-200", 8));
+    /// // -0 is ok, even for an unsigned type
+    /// assert_eq!((Some(0),2), u8::from_radix_signed_checked(b"-0", 8));
+    /// // Negative values for unsigned types are handled as `None`.
+    /// assert_eq!((None,2), u8::from_radix_signed_checked(b"-1", 8));
+    /// // Overflow is indicated by `None`
+    /// assert_eq!((None,4), i8::from_radix_signed_checked(b"+200", 8));
+    /// assert_eq!((None,4), i8::from_radix_signed_checked(b"-201", 8));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns a tuple with two numbers. The first is the integer parsed or zero if no digit has
+    /// been found. None, if there were too many, or too high dighits and the parsing overflowed.
+    /// The second is the index of the byte right after the parsed number. If the second element is
+    /// zero the slice did not start with a digit of the radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    fn from_radix_signed_checked(_: &[u8], radix: u32) -> (Option<Self>, usize);
+
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_radix_signed_checked`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadixSignedChecked;
+    /// assert_eq!(
+    ///     (Some(-5), &b"b"[..]),
+    ///     i32::from_radix_signed_checked_with_rest(b"-101b", 2)
+    /// );
+    /// assert_eq!((Some(0), &b"2"[..]), i32::from_radix_signed_checked_with_rest(b"-2", 2));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed integer, or `None` if the slice does not start with a number or the number
+    /// overflowed. The bytes following the number, or all of `text` if there is no number.
+    fn from_radix_signed_checked_with_rest(text: &[u8], radix: u32) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_radix_signed_checked(text, radix);
+        split_rest(text, number, used)
+    }
+}
+
+/// Types implementing this trait can be parsed from a positional numeral system with a radix known
+/// at compile time. Acts much like [`FromRadix`], but since the radix is a constant, the number of
+/// digits which can be parsed without overflow is computed at compile time and multiplications
+/// with a radix which is a power of two can be optimized into shifts.
+pub trait FromConstRadix: Sized {
+    /// Parses an integer from a slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromConstRadix;
+    /// // Parsing octal digits from a slice
+    /// assert_eq!((493,3), u32::from_const_radix::<8>(b"755"));
+    /// // Additional bytes after the number are ignored
+    /// assert_eq!((5,3), u32::from_const_radix::<2>(b"101 is five in binary"));
+    /// // (0,0) is returned if the slice does not start with a digit of the radix
+    /// assert_eq!((0,0), u32::from_const_radix::<8>(b"9"));
+    /// // Letters are allowed for radices above 10, regardless of their case
+    /// assert_eq!((1295,2), u32::from_const_radix::<36>(b"zZ"));
+    /// ```
+    ///
+    /// A radix outside of the range from 2 to 36 is rejected at compile time.
+    ///
+    /// ```compile_fail
+    /// use atoi::FromConstRadix;
+    /// u32::from_const_radix::<37>(b"42");
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns a tuple with two numbers. The first is the integer parsed or zero, the second is the
+    /// index of the byte right after the parsed number. If the second element is zero the slice
+    /// did not start with a digit of the radix.
+    fn from_const_radix<const RADIX: u32>(_: &[u8]) -> (Self, usize);
+
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_const_radix`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromConstRadix;
+    /// assert_eq!(
+    ///     (Some(493), &b" rwx"[..]),
+    ///     u32::from_const_radix_with_rest::<8>(b"755 rwx")
+    /// );
+    /// assert_eq!((None, &b"9"[..]), u32::from_const_radix_with_rest::<8>(b"9"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed number, or `None` if the slice does not start with a number. The bytes
+    /// following the number, or all of `text` if there is no number.
+    fn from_const_radix_with_rest<const RADIX: u32>(text: &[u8]) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_const_radix::<RADIX>(text);
+        split_rest(text, Some(number), used)
+    }
+}
+
+/// Types implementing this trait can be parsed from a positional numeral system with a radix known
+/// at compile time. Acts much like [`FromConstRadix`], but performs additional checks for
+/// overflows.
+pub trait FromConstRadixChecked: Sized {
+    /// Parses an integer from a slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromConstRadixChecked;
+    /// // Parsing binary digits from a slice
+    /// assert_eq!((Some(10),4), u8::from_const_radix_checked::<2>(b"1010"));
+    /// // (0,0) is returned if the slice does not start with a digit of the radix
+    /// assert_eq!((Some(0),0), u8::from_const_radix_checked::<2>(b"2"));
+    /// // Overflow is indicated by `None`
+    /// assert_eq!((None, 3), u8::from_const_radix_checked::<8>(b"400"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns a tuple with two numbers. The first is the integer parsed or zero if no digit has
+    /// been found. None, if there were too many, or too high dighits and the parsing overflowed.
+    /// The second is the index of the byte right after the parsed number. If the second element is
+    /// zero the slice did not start with a digit of the radix.
+    fn from_const_radix_checked<const RADIX: u32>(_: &[u8]) -> (Option<Self>, usize);
+
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_const_radix_checked`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromConstRadixChecked;
+    /// assert_eq!(
+    ///     (Some(493), &b" rwx"[..]),
+    ///     u32::from_const_radix_checked_with_rest::<8>(b"755 rwx")
+    /// );
+    /// assert_eq!((None, &b"9"[..]), u32::from_const_radix_checked_with_rest::<8>(b"9"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed integer, or `None` if the slice does not start with a number or the number
+    /// overflowed. The bytes following the number, or all of `text` if there is no number.
+    fn from_const_radix_checked_with_rest<const RADIX: u32>(text: &[u8]) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_const_radix_checked::<RADIX>(text);
+        split_rest(text, number, used)
+    }
+}
+
+/// Types implementing this trait can be parsed from a positional numeral system with a radix known
+/// at compile time. This trait allows for an additional sign character (`+` or `-`) in front of
+/// the actual number in order, to allow for parsing negative values.
+pub trait FromConstRadixSigned: Sized {
+    /// Parses an integer from a slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromConstRadixSigned;
+    /// // Signs are allowed
+    /// assert_eq!((-493,4), i32::from_const_radix_signed::<8>(b"-755"));
+    /// assert_eq!((493,4), i32::from_const_radix_signed::<8>(b"+755"));
+    /// // (0,0) is returned if the slice does not start with a digit of the radix
+    /// assert_eq!((0,0), i32::from_const_radix_signed::<8>(b"Sadly we do not know the question"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns a tuple with two numbers. The first is the integer parsed or zero, the second is the
+    /// index of the byte right after the parsed number. If the second element is zero the slice
+    /// did not start with a digit of the radix.
+    fn from_const_radix_signed<const RADIX: u32>(_: &[u8]) -> (Self, usize);
+
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_const_radix_signed`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromConstRadixSigned;
+    /// assert_eq!(
+    ///     (Some(-493), &b" rwx"[..]),
+    ///     i32::from_const_radix_signed_with_rest::<8>(b"-755 rwx")
+    /// );
+    /// assert_eq!((Some(0), &b"9"[..]), i32::from_const_radix_signed_with_rest::<8>(b"-9"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed number, or `None` if the slice does not start with a number. The bytes
+    /// following the number, or all of `text` if there is no number.
+    fn from_const_radix_signed_with_rest<const RADIX: u32>(text: &[u8]) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_const_radix_signed::<RADIX>(text);
+        split_rest(text, Some(number), used)
+    }
+}
+
+/// Types implementing this trait can be parsed from a positional numeral system with a radix known
+/// at compile time. Acts much like [`FromConstRadixSigned`], but performs additional checks for
+/// overflows.
+pub trait FromConstRadixSignedChecked: FromConstRadixSigned {
+    /// Parses an integer from a slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromConstRadixSignedChecked;
+    /// assert_eq!((Some(-128),4), i8::from_const_radix_signed_checked::<8>(b"-200"));
+    /// // -0 is ok, even for an unsigned type
+    /// assert_eq!((Some(0),2), u8::from_const_radix_signed_checked::<8>(b"-0"));
+    /// // Negative values for unsigned types are handled as `None`.
+    /// assert_eq!((None,2), u8::from_const_radix_signed_checked::<8>(b"-1"));
+    /// // Overflow is indicated by `None`
+    /// assert_eq!((None,4), i8::from_const_radix_signed_checked::<8>(b"+200"));
+    /// assert_eq!((None,4), i8::from_const_radix_signed_checked::<8>(b"-201"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns a tuple with two numbers. The first is the integer parsed or zero if no digit has
+    /// been found. None, if there were too many, or too high dighits and the parsing overflowed.
+    /// The second is the index of the byte right after the parsed number. If the second element is
+    /// zero the slice did not start with a digit of the radix.
+    fn from_const_radix_signed_checked<const RADIX: u32>(_: &[u8]) -> (Option<Self>, usize);
+
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_const_radix_signed_checked`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromConstRadixSignedChecked;
+    /// assert_eq!(
+    ///     (Some(-493), &b" rwx"[..]),
+    ///     i32::from_const_radix_signed_checked_with_rest::<8>(b"-755 rwx")
+    /// );
+    /// assert_eq!(
+    ///     (Some(0), &b"9"[..]),
+    ///     i32::from_const_radix_signed_checked_with_rest::<8>(b"-9")
+    /// );
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed integer, or `None` if the slice does not start with a number or the number
+    /// overflowed. The bytes following the number, or all of `text` if there is no number.
+    fn from_const_radix_signed_checked_with_rest<const RADIX: u32>(
+        text: &[u8],
+    ) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_const_radix_signed_checked::<RADIX>(text);
+        split_rest(text, number, used)
+    }
+}
+
+/// Types implementing this trait can be parsed from a positional numeral system with radix 10.
+/// Acts much like `FromRadix10Checked`, but clamps numbers too large for the type to its maximum.
+pub trait FromRadix10Saturating: Sized {
+    /// Parses an integer from a slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadix10Saturating;
+    /// assert_eq!((42,2), u8::from_radix_10_saturating(b"42"));
+    /// // (0,0) is returned if the slice does not start with a digit
+    /// assert_eq!((0,0), u8::from_radix_10_saturating(b"Sadly we do not know the question"));
+    /// // Overflow results in the maximum, yet all digits are consumed
+    /// assert_eq!((255, 5), u8::from_radix_10_saturating(b"12345"));
+    /// assert_eq!((127, 3), i8::from_radix_10_saturating(b"128"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns a tuple with two numbers. The first is the integer parsed, zero if no digit has
+    /// been found or the maximum of the type in case of overflow. The second is the index of the
+    /// byte right after the parsed number. If the second element is zero the slice did not start
+    /// with an ASCII digit.
+    fn from_radix_10_saturating(_: &[u8]) -> (Self, usize);
+
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_radix_10_saturating`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadix10Saturating;
+    /// assert_eq!(
+    ///     (Some(255), &b" x"[..]),
+    ///     u8::from_radix_10_saturating_with_rest(b"300 x")
+    /// );
+    /// assert_eq!((None, &b"x"[..]), u8::from_radix_10_saturating_with_rest(b"x"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed number, or `None` if the slice does not start with a number. The bytes
+    /// following the number, or all of `text` if there is no number.
+    fn from_radix_10_saturating_with_rest(text: &[u8]) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_radix_10_saturating(text);
+        split_rest(text, Some(number), used)
+    }
+}
+
+/// Types implementing this trait can be parsed from a positional numeral system with radix 10.
+/// Acts much like `FromRadix10SignedChecked`, but clamps numbers out of range for the type to its
+/// minimum or maximum.
+pub trait FromRadix10SignedSaturating: Sized {
+    /// Parses an integer from a slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadix10SignedSaturating;
+    /// assert_eq!((-42,3), i8::from_radix_10_signed_saturating(b"-42"));
+    /// assert_eq!((-128,4), i8::from_radix_10_signed_saturating(b"-300"));
+    /// assert_eq!((127,4), i8::from_radix_10_signed_saturating(b"+300"));
+    /// // Negative numbers are clamped to zero for unsigned types
+    /// assert_eq!((0,2), u8::from_radix_10_signed_saturating(b"-1"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns a tuple with two numbers. The first is the integer parsed, zero if no digit has
+    /// been found or the minimum or maximum of the type in case of overflow. The second is the
+    /// index of the byte right after the parsed number. If the second element is zero the slice
+    /// did not start with an ASCII digit or sign.
+    fn from_radix_10_signed_saturating(_: &[u8]) -> (Self, usize);
+
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_radix_10_signed_saturating`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadix10SignedSaturating;
+    /// assert_eq!(
+    ///     (Some(-128), &b" x"[..]),
+    ///     i8::from_radix_10_signed_saturating_with_rest(b"-300 x")
+    /// );
+    /// assert_eq!((Some(0), &b"x"[..]), i8::from_radix_10_signed_saturating_with_rest(b"-x"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed number, or `None` if the slice does not start with a number. The bytes
+    /// following the number, or all of `text` if there is no number.
+    fn from_radix_10_signed_saturating_with_rest(text: &[u8]) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_radix_10_signed_saturating(text);
+        split_rest(text, Some(number), used)
+    }
+}
+
+/// Types implementing this trait can be parsed from a positional numeral system with radix 16.
+/// Acts much like `FromRadix16Checked`, but clamps numbers too large for the type to its maximum.
+pub trait FromRadix16Saturating: Sized {
+    /// Parses an integer from a slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadix16Saturating;
+    /// assert_eq!((0x2a,2), u8::from_radix_16_saturating(b"2a"));
+    /// // Overflow results in the maximum, yet all digits are consumed
+    /// assert_eq!((0xffff,5), u16::from_radix_16_saturating(b"12345"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns a tuple with two numbers. The first is the integer parsed, zero if no digit has
+    /// been found or the maximum of the type in case of overflow. The second is the index of the
+    /// byte right after the parsed number. If the second element is zero the slice did not start
+    /// with a hexadecimal digit.
+    fn from_radix_16_saturating(_: &[u8]) -> (Self, usize);
+
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_radix_16_saturating`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadix16Saturating;
+    /// assert_eq!(
+    ///     (Some(255), &b" x"[..]),
+    ///     u8::from_radix_16_saturating_with_rest(b"100 x")
+    /// );
+    /// assert_eq!((None, &b"x"[..]), u8::from_radix_16_saturating_with_rest(b"x"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed number, or `None` if the slice does not start with a number. The bytes
+    /// following the number, or all of `text` if there is no number.
+    fn from_radix_16_saturating_with_rest(text: &[u8]) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_radix_16_saturating(text);
+        split_rest(text, Some(number), used)
+    }
+}
+
+/// Types implementing this trait can be parsed from a positional numeral system with radix 16.
+/// Acts much like `FromRadix16SignedChecked`, but clamps numbers out of range for the type to its
+/// minimum or maximum.
+pub trait FromRadix16SignedSaturating: Sized {
+    /// Parses an integer from a slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadix16SignedSaturating;
+    /// assert_eq!((-0x2a,3), i8::from_radix_16_signed_saturating(b"-2a"));
+    /// assert_eq!((-128,4), i8::from_radix_16_signed_saturating(b"-100"));
+    /// assert_eq!((127,4), i8::from_radix_16_signed_saturating(b"+100"));
+    /// // Negative numbers are clamped to zero for unsigned types
+    /// assert_eq!((0,2), u8::from_radix_16_signed_saturating(b"-1"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns a tuple with two numbers. The first is the integer parsed, zero if no digit has
+    /// been found or the minimum or maximum of the type in case of overflow. The second is the
+    /// index of the byte right after the parsed number. If the second element is zero the slice
+    /// did not start with a hexadecimal digit or sign.
+    fn from_radix_16_signed_saturating(_: &[u8]) -> (Self, usize);
+
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_radix_16_signed_saturating`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadix16SignedSaturating;
+    /// assert_eq!(
+    ///     (Some(-128), &b" x"[..]),
+    ///     i8::from_radix_16_signed_saturating_with_rest(b"-100 x")
+    /// );
+    /// assert_eq!((None, &b"x"[..]), i8::from_radix_16_signed_saturating_with_rest(b"x"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed number, or `None` if the slice does not start with a number. The bytes
+    /// following the number, or all of `text` if there is no number.
+    fn from_radix_16_signed_saturating_with_rest(text: &[u8]) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_radix_16_signed_saturating(text);
+        split_rest(text, Some(number), used)
+    }
+}
+
+/// Types implementing this trait can be parsed from a positional numeral system with a radix
+/// specified at runtime. Acts much like `FromRadixChecked`, but clamps numbers too large for the
+/// type to its maximum.
+pub trait FromRadixSaturating: Sized {
+    /// Parses an integer from a slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadixSaturating;
+    /// assert_eq!((10,4), u8::from_radix_saturating(b"1010", 2));
+    /// // Overflow results in the maximum, yet all digits are consumed
+    /// assert_eq!((255,3), u8::from_radix_saturating(b"400", 8));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns a tuple with two numbers. The first is the integer parsed, zero if no digit has
+    /// been found or the maximum of the type in case of overflow. The second is the index of the
+    /// byte right after the parsed number. If the second element is zero the slice did not start
+    /// with a digit of the radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    fn from_radix_saturating(_: &[u8], radix: u32) -> (Self, usize);
+
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_radix_saturating`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadixSaturating;
+    /// assert_eq!(
+    ///     (Some(255), &b" rwx"[..]),
+    ///     u8::from_radix_saturating_with_rest(b"400 rwx", 8)
+    /// );
+    /// assert_eq!((None, &b"9"[..]), u8::from_radix_saturating_with_rest(b"9", 8));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed number, or `None` if the slice does not start with a number. The bytes
+    /// following the number, or all of `text` if there is no number.
+    fn from_radix_saturating_with_rest(text: &[u8], radix: u32) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_radix_saturating(text, radix);
+        split_rest(text, Some(number), used)
+    }
+}
+
+/// Types implementing this trait can be parsed from a positional numeral system with a radix
+/// specified at runtime. Acts much like `FromRadixSignedChecked`, but clamps numbers out of range
+/// for the type to its minimum or maximum.
+pub trait FromRadixSignedSaturating: Sized {
+    /// Parses an integer from a slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadixSignedSaturating;
+    /// assert_eq!((-5,4), i8::from_radix_signed_saturating(b"-101", 2));
+    /// assert_eq!((-128,4), i8::from_radix_signed_saturating(b"-777", 8));
+    /// assert_eq!((127,4), i8::from_radix_signed_saturating(b"+777", 8));
+    /// // Negative numbers are clamped to zero for unsigned types
+    /// assert_eq!((0,2), u8::from_radix_signed_saturating(b"-1", 8));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns a tuple with two numbers. The first is the integer parsed, zero if no digit has
+    /// been found or the minimum or maximum of the type in case of overflow. The second is the
+    /// index of the byte right after the parsed number. If the second element is zero the slice
+    /// did not start with a digit of the radix or a sign.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    fn from_radix_signed_saturating(_: &[u8], radix: u32) -> (Self, usize);
+
+    /// Parses an integer from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_radix_signed_saturating`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadixSignedSaturating;
+    /// assert_eq!(
+    ///     (Some(-128), &b"b"[..]),
+    ///     i8::from_radix_signed_saturating_with_rest(b"-777b", 8)
+    /// );
+    /// assert_eq!((None, &b"9"[..]), i8::from_radix_signed_saturating_with_rest(b"9", 8));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed number, or `None` if the slice does not start with a number. The bytes
+    /// following the number, or all of `text` if there is no number.
+    fn from_radix_signed_saturating_with_rest(text: &[u8], radix: u32) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_radix_signed_saturating(text, radix);
+        split_rest(text, Some(number), used)
+    }
+}
+
+/// Floating point types implementing this trait can be parsed from a decimal representation
+pub trait FromRadix10Float: Sized {
+    /// Parses a floating point number from a slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadix10Float;
+    /// // Parsing a number with decimal point and exponent from a slice
+    /// assert_eq!((1234.5,8), f64::from_radix_10_float(b"1.2345e3"));
+    /// // Additional bytes after the number are ignored
+    /// assert_eq!((42.,3), f64::from_radix_10_float(b"42. is the answer"));
+    /// // An exponent without digits is not part of the number
+    /// assert_eq!((42.,2), f64::from_radix_10_float(b"42e"));
+    /// // Digits may be omitted on either side of the decimal point
+    /// assert_eq!((-0.5,3), f32::from_radix_10_float(b"-.5"));
+    /// // (0,0) is returned if the slice does not start with a number
+    /// assert_eq!((0.,0), f64::from_radix_10_float(b"Sadly we do not know the question"));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns a tuple with two numbers. The first is the number parsed or zero, the second is the
+    /// index of the byte right after the parsed number. If the second element is zero the slice
+    /// did not start with a number.
+    fn from_radix_10_float(_: &[u8]) -> (Self, usize);
+
+    /// Parses a number from a slice and returns it together with the bytes following it. Acts
+    /// like [`Self::from_radix_10_float`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromRadix10Float;
+    /// assert_eq!(
+    ///     (Some(0.25), &b";"[..]),
+    ///     f64::from_radix_10_float_with_rest(b"2.5e-1;")
+    /// );
+    /// assert_eq!((None, &b"."[..]), f64::from_radix_10_float_with_rest(b"."));
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// The parsed number, or `None` if the slice does not start with a number. The bytes
+    /// following the number, or all of `text` if there is no number.
+    fn from_radix_10_float_with_rest(text: &[u8]) -> (Option<Self>, &[u8]) {
+        let (number, used) = Self::from_radix_10_float(text);
+        split_rest(text, Some(number), used)
+    }
+}
+
+/// Types implementing this trait can be formatted into a positional numeral system with radix 10.
+/// This is the inverse of [`FromRadix10Signed`].
+pub trait ToRadix10 {
+    /// Writes the decimal representation of the integer to the start of `buffer`. Negative numbers
+    /// are preceded by a `-`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::{MaxNumDigits, ToRadix10};
+    /// // A sign and the ten digits of `i32::MIN`
+    /// let mut buffer = [0; 11];
+    /// assert_eq!(buffer.len(), i32::max_num_bytes(10));
+    /// let len = 42i32.to_radix_10(&mut buffer);
+    /// assert_eq!(b"42", &buffer[..len]);
+    /// let len = i32::MIN.to_radix_10(&mut buffer);
+    /// assert_eq!(b"-2147483648", &buffer[..len]);
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns the number of bytes written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` is too small to hold the representation. A buffer of
+    /// [`MaxNumDigits::max_num_bytes`] bytes for radix 10 is always large enough.
+    fn to_radix_10(self, buffer: &mut [u8]) -> usize;
+}
+
+/// Types implementing this trait can be formatted into a positional numeral system with radix 16.
+/// This is the inverse of [`FromRadix16Signed`].
+pub trait ToRadix16 {
+    /// Writes the hexadecimal representation of the integer to the start of `buffer`, using
+    /// lowercase letters. Negative numbers are preceded by a `-`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::{MaxNumDigits, ToRadix16};
+    /// // A sign and the eight digits of `i32::MIN`
+    /// let mut buffer = [0; 9];
+    /// assert_eq!(buffer.len(), i32::max_num_bytes(16));
+    /// let len = 42i32.to_radix_16(&mut buffer);
+    /// assert_eq!(b"2a", &buffer[..len]);
+    /// let len = (-31i32).to_radix_16(&mut buffer);
+    /// assert_eq!(b"-1f", &buffer[..len]);
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// Returns the number of bytes written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` is too small to hold the representation. A buffer of
+    /// [`MaxNumDigits::max_num_bytes`] bytes for radix 16 is always large enough.
+    fn to_radix_16(self, buffer: &mut [u8]) -> usize;
+}
+
+/// A bounded integer, whose representation can overflow and therefore can only store a maximum
+/// number of digits
+pub trait MaxNumDigits {
+    /// Given a representation with a radix character I, what is the maximum number of digits we can
+    /// parse without the integer overflowing for sure?
+    fn max_num_digits(radix: Self) -> usize;
+
+    /// Returns the maximum number of digits a negative representation of `I` can have depending on
+    /// `radix`.
+    fn max_num_digits_negative(radix: Self) -> usize;
+
+    /// Returns the maximum number of bytes a representation of `I` can have depending on `radix`,
+    /// including the sign of negative numbers. A buffer of this length is always large enough for
+    /// [`ToRadix10`] or [`ToRadix16`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::MaxNumDigits;
+    /// assert_eq!(3, u8::max_num_bytes(10));
+    /// assert_eq!(4, i8::max_num_bytes(10));
+    /// assert_eq!(9, i8::max_num_bytes(2));
+    /// ```
+    fn max_num_bytes(radix: Self) -> usize
+    where
+        Self: Sized + Copy,
+    {
+        max(
+            Self::max_num_digits(radix),
+            Self::max_num_digits_negative(radix) + 1,
+        )
+    }
+}
+
+/// Representation of a numerical sign
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sign {
+    Plus,
+    Minus,
+}
+
+impl Sign {
+    /// Trys to convert an ascii character into a `Sign`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::Sign;
+    /// assert_eq!(Some(Sign::Plus), Sign::try_from(b'+'));
+    /// assert_eq!(Some(Sign::Minus), Sign::try_from(b'-'));
+    /// assert_eq!(None, Sign::try_from(b'1'));
+    /// ```
+    pub fn try_from(byte: u8) -> Option<Sign> {
+        match byte {
+            b'+' => Some(Sign::Plus),
+            b'-' => Some(Sign::Minus),
+            _ => None,
+        }
+    }
+
+    /// Returns either `+1` or `-1`
+    pub fn signum<I>(self) -> I
+    where
+        I: Signed,
+    {
+        match self {
+            Sign::Plus => I::one(),
+            Sign::Minus => -I::one(),
+        }
+    }
+}
+
+/// Construct an instance of a numerical type using the byte representation of a radix 10 digit,
+/// e.g. b'7' -> 7.
+pub trait FromDigit: Sized {
+    /// Convert ASCII digit (e.g. b'7) into numeric representation (`7`). `None` if the character
+    /// given does not represent a digit in ASCII.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::FromDigit;
+    /// assert_eq!(Some(5), u32::from_digit(b'5'));
+    /// assert_eq!(None, u32::from_digit(b'x'));
+    /// ```
+    fn from_digit(digit: u8) -> Option<Self>;
+}
 
 trait FromHexDigit: Sized {
     fn from_hex_digit(digit: u8) -> Option<Self>;
 }
 
+trait FromRadixDigit: Sized {
+    fn from_radix_digit(digit: u8, radix: u32) -> Option<Self>;
+}
+
+/// Panics if `radix` is not supported by [`FromRadix`] and its sibling traits.
+#[inline]
+fn assert_radix(radix: u32) {
+    assert!(
+        (2..=36).contains(&radix),
+        "radix must be in the range from 2 to 36, but is {radix}"
+    );
+}
+
+/// Fails compilation if `RADIX` is not supported by [`FromConstRadix`] and its sibling traits.
+#[inline]
+fn assert_const_radix<const RADIX: u32>() {
+    const {
+        assert!(
+            2 <= RADIX && RADIX <= 36,
+            "radix must be in the range from 2 to 36"
+        )
+    }
+}
+
+/// Splits off the bytes following a number from `text`, given the number of bytes `used` by it. Like
+/// [`atoi`], a sign without digits counts as zero.
+#[inline]
+fn split_rest<I>(text: &[u8], number: Option<I>, used: usize) -> (Option<I>, &[u8]) {
+    if used == 0 {
+        (None, text)
+    } else {
+        (number, &text[used..])
+    }
+}
+
 /// Converts an ascii character to digit
 ///
 /// # Example
@@ -390,6 +1969,34 @@ mod test {
         assert_eq!(3, Integer::<i8>::max_num_digits_negative(Integer(10)));
     }
 
+    #[test]
+    fn max_bytes() {
+        assert_eq!(11, i32::max_num_bytes(10));
+        assert_eq!(10, u32::max_num_bytes(10));
+        assert_eq!(40, i128::max_num_bytes(10));
+        assert_eq!(39, u128::max_num_bytes(10));
+        assert_eq!(33, i128::max_num_bytes(16));
+        assert_eq!(9, i8::max_num_bytes(2));
+    }
+
+    #[test]
+    fn format_integer() {
+        let mut buffer = [0; 4];
+        let len = Integer(-128i8).to_radix_10(&mut buffer);
+        assert_eq!(b"-128", &buffer[..len]);
+        let len = Integer(-128i8).to_radix_16(&mut buffer);
+        assert_eq!(b"-80", &buffer[..len]);
+        let len = Integer(0u8).to_radix_10(&mut buffer);
+        assert_eq!(b"0", &buffer[..len]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn format_into_too_small_buffer() {
+        let mut buffer = [0; 3];
+        (-128i8).to_radix_10(&mut buffer);
+    }
+
     #[test]
     fn checked_parsing() {
         assert_eq!((Some(255), 3), u8::from_radix_10_checked(b"255"));
@@ -411,6 +2018,189 @@ mod test {
         assert_eq!((Some(25), 2), u8::from_radix_16_checked(b"19!Blub"));
     }
 
+    #[test]
+    fn signed_checked_parsing_radix_16() {
+        assert_eq!((Some(127), 2), i8::from_radix_16_signed_checked(b"7F"));
+        assert_eq!((None, 2), i8::from_radix_16_signed_checked(b"80"));
+        assert_eq!((Some(-128), 3), i8::from_radix_16_signed_checked(b"-80"));
+        assert_eq!((None, 3), i8::from_radix_16_signed_checked(b"-81"));
+        assert_eq!((None, 4), i8::from_radix_16_signed_checked(b"-100"));
+        assert_eq!(
+            (Some(-25), 3),
+            i8::from_radix_16_signed_checked(b"-19!Blub")
+        );
+        assert_eq!((Some(0), 1), i8::from_radix_16_signed_checked(b"-"));
+        let (number, used) = Integer::<i16>::from_radix_16_signed_checked(b"-8000");
+        assert_eq!((Some(i16::MIN), 5), (number.map(|n| n.0), used));
+    }
+
+    #[test]
+    fn checked_parsing_radix() {
+        assert_eq!((Some(255), 8), u8::from_radix_checked(b"11111111", 2));
+        assert_eq!((None, 9), u8::from_radix_checked(b"100000000", 2));
+        assert_eq!((Some(255), 3), u8::from_radix_checked(b"377", 8));
+        assert_eq!((None, 3), u8::from_radix_checked(b"400", 8));
+        assert_eq!((Some(255), 2), u8::from_radix_checked(b"73", 36));
+        assert_eq!((None, 2), u8::from_radix_checked(b"74", 36));
+        assert_eq!((Some(7), 3), u8::from_radix_checked(b"1112", 2));
+    }
+
+    #[test]
+    fn signed_checked_parsing_radix() {
+        assert_eq!(
+            (Some(-128), 9),
+            i8::from_radix_signed_checked(b"-10000000", 2)
+        );
+        assert_eq!((None, 9), i8::from_radix_signed_checked(b"-10000001", 2));
+        assert_eq!(
+            (Some(127), 8),
+            i8::from_radix_signed_checked(b"+1111111", 2)
+        );
+        assert_eq!((None, 9), i8::from_radix_signed_checked(b"+10000000", 2));
+        assert_eq!((Some(-71), 3), i64::from_radix_signed_checked(b"-1Z", 36));
+    }
+
+    #[test]
+    fn integer_parsing_radix() {
+        let (number, used) = Integer::<i32>::from_radix_signed_checked(b"-755", 8);
+        assert_eq!((Some(-493), 4), (number.map(|n| n.0), used));
+        let (number, used) = Integer::<u8>::from_radix_checked(b"400", 8);
+        assert_eq!((None, 3), (number.map(|n| n.0), used));
+    }
+
+    #[test]
+    #[should_panic]
+    fn radix_out_of_range() {
+        u32::from_radix(b"42", 37);
+    }
+
+    #[test]
+    fn signed_checked_parsing_beyond_safe_digits() {
+        assert_eq!(
+            (Some(-32768), 6),
+            i16::from_radix_10_signed_checked(b"-32768")
+        );
+        assert_eq!((None, 6), i16::from_radix_10_signed_checked(b"-32769"));
+        assert_eq!((None, 6), i16::from_radix_10_signed_checked(b"-99999"));
+    }
+
+    #[test]
+    fn checked_parsing_const_radix() {
+        assert_eq!(
+            (Some(u128::MAX), 32),
+            u128::from_const_radix_checked::<16>(&[b'f'; 32])
+        );
+        assert_eq!(
+            (None, 33),
+            u128::from_const_radix_checked::<16>(&[b'f'; 33])
+        );
+        assert_eq!(
+            (Some(i8::MIN), 9),
+            i8::from_const_radix_signed_checked::<2>(b"-10000000")
+        );
+        assert_eq!(
+            (None, 9),
+            i8::from_const_radix_signed_checked::<2>(b"-10000001")
+        );
+        assert_eq!(
+            (Some(35), 1),
+            i8::from_const_radix_signed_checked::<36>(b"z")
+        );
+    }
+
+    #[test]
+    fn atoi_parses_sign_without_digits_as_zero() {
+        assert_eq!(Some(0), atoi::<i32>(b"-"));
+        assert_eq!(Some(0), atoi::<i32>(b"+"));
+        assert_eq!(Some(0), atoi::<i32>(b"-x"));
+        assert_eq!(Some(0), atoi::<i32>(b"-0"));
+        assert_eq!(Err(ParseIntegerError::SignOnly), try_atoi::<i32>(b"-"));
+        assert_eq!(
+            Err(ParseIntegerError::InvalidDigit { position: 1 }),
+            try_atoi::<i32>(b"-x")
+        );
+    }
+
+    #[test]
+    fn try_parsing_reports_errors() {
+        assert_eq!(
+            Err(ParseIntegerError::InvalidDigit { position: 0 }),
+            try_atoi::<i32>(b" 42")
+        );
+        assert_eq!(
+            Err(ParseIntegerError::NegOverflow),
+            try_atoi::<i128>(b"-170141183460469231731687303715884105729")
+        );
+        assert_eq!(
+            Err(ParseIntegerError::PosOverflow),
+            u8::try_from_radix_10_checked(b"1000")
+        );
+        assert_eq!(Ok((0, 2)), try_atoi::<u8>(b"-0"));
+    }
+
+    #[test]
+    fn parse_from_as_ref_inputs() {
+        assert_eq!(Some(-42), atoi::<i32>("-42"));
+        assert_eq!(Ok((42, 2)), try_atoi::<u8>("42 is the answer"));
+        assert_eq!(Some(2.5), atof::<f64>("2.5"));
+        assert_eq!(Ok(0xff), parse_exact_radix_16::<u8>(*b"ff"));
+        assert_eq!(Ok((1, 2, 3)), parse_row::<(u8, u8, u8)>("1,2,3", b','));
+        let sum: u32 = numbers::<u32>("1 2 3").filter_map(|(n, _)| n).sum();
+        assert_eq!(6, sum);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn parse_from_owned_inputs() {
+        use std::borrow::Cow;
+
+        let text = String::from("1234");
+        assert_eq!(Some(1234), atoi::<u32>(&text));
+        assert_eq!(Some(1234), atoi::<u32>(text.clone()));
+        assert_eq!(Ok(1234), parse_exact::<u32>(text.into_bytes()));
+        assert_eq!(Some(-1), atoi::<i8>(Cow::Borrowed(&b"-1"[..])));
+        assert_eq!(Some(-1), atoi::<i8>(Cow::<[u8]>::Owned(vec![b'-', b'1'])));
+    }
+
+    #[test]
+    fn parse_with_rest() {
+        // Chaining calls
+        let (x, rest) = atoi_with_rest::<i32>("-1 2");
+        let (y, rest) = atoi_with_rest::<i32>(&rest[1..]);
+        assert_eq!((Some(-1), Some(2), &b""[..]), (x, y, rest));
+        // Sign without digits, like `atoi`
+        assert_eq!((Some(0), &b""[..]), atoi_with_rest::<i32>(b"+"));
+        assert_eq!(
+            (Some(0), &b"x"[..]),
+            i32::from_radix_10_signed_with_rest(b"-x")
+        );
+        assert_eq!(
+            (Some(0), &b""[..]),
+            i32::from_radix_signed_checked_with_rest(b"-", 7)
+        );
+        assert_eq!(
+            (Some(0), &b"8"[..]),
+            i32::from_const_radix_signed_checked_with_rest::<7>(b"-8")
+        );
+        assert_eq!(atoi::<i32>(b"-"), atoi_with_rest::<i32>(b"-").0);
+        // Empty input
+        assert_eq!((None, &b""[..]), u32::from_radix_16_with_rest(b""));
+        assert_eq!((None, &b""[..]), f32::from_radix_10_float_with_rest(b""));
+        // Overflow consumes all digits
+        assert_eq!(
+            (None, &b"x"[..]),
+            u8::from_radix_checked_with_rest(b"100000000x", 2)
+        );
+        assert_eq!(
+            (Some(255), &b"x"[..]),
+            u8::from_radix_16_saturating_with_rest(b"100000000x")
+        );
+        assert_eq!(
+            (Some(-128), &b"x"[..]),
+            i8::from_radix_10_signed_saturating_with_rest(b"-1000x")
+        );
+    }
+
     #[test]
     fn ascii_to_digit_wrapper() {
         assert_eq!(Some(0), ascii_to_digit(b'0'));
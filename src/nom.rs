@@ -0,0 +1,162 @@
+//! Parser combinators for `nom`, built on the checked parsing traits of this crate.
+//! Requires the `nom` feature.
+//!
+//! The parsers accept `&[u8]` as well as `&str` input. Like the *complete* parsers of `nom`, they
+//! treat the end of the input as the end of the number. Failures are reported as [`Err::Error`]
+//! pointing at the start of the number, with an error kind telling apart a missing number from an
+//! overflow:
+//!
+//! * [`ErrorKind::Digit`] or [`ErrorKind::HexDigit`] if the input does not start with a number.
+//! * [`ErrorKind::TooLarge`] if the number does not fit into the integer type.
+//! * [`ErrorKind::Verify`] if the number is zero, but the integer type is non zero.
+//!
+//! ```
+//! use atoi::nom::{dec_int, hex_uint};
+//! use nom::{
+//!     bytes::complete::tag,
+//!     error::{Error, ErrorKind},
+//!     sequence::separated_pair,
+//!     Err, IResult, Parser,
+//! };
+//!
+//! fn range(input: &[u8]) -> IResult<&[u8], (i64, u8)> {
+//!     separated_pair(dec_int, tag("..0x"), hex_uint).parse(input)
+//! }
+//!
+//! assert_eq!(Ok((&b";"[..], (-12, 0xff))), range(b"-12..0xff;"));
+//! assert_eq!(
+//!     Err(Err::Error(Error::new(&b"100"[..], ErrorKind::TooLarge))),
+//!     range(b"-12..0x100")
+//! );
+//! assert_eq!(
+//!     Err(Err::Error(Error::new(&b"+..0x1"[..], ErrorKind::Digit))),
+//!     range(b"+..0x1")
+//! );
+//! ```
+
+use ::nom::{
+    AsBytes, Err, IResult, Input,
+    error::{ErrorKind, ParseError},
+};
+
+use crate::{
+    FromRadix10Checked, FromRadix10SignedChecked, FromRadix16Checked, FromRadix16SignedChecked,
+    ParseIntegerError,
+};
+
+/// Parses a decimal integer without sign. See [`FromRadix10Checked`].
+pub fn dec_uint<I, T, E>(input: T) -> IResult<T, I, E>
+where
+    I: FromRadix10Checked,
+    T: Input + AsBytes,
+    E: ParseError<T>,
+{
+    let result = I::try_from_radix_10_checked(input.as_bytes());
+    finish(input, result, ErrorKind::Digit)
+}
+
+/// Parses a decimal integer with an optional sign. See [`FromRadix10SignedChecked`].
+pub fn dec_int<I, T, E>(input: T) -> IResult<T, I, E>
+where
+    I: FromRadix10SignedChecked,
+    T: Input + AsBytes,
+    E: ParseError<T>,
+{
+    let result = I::try_from_radix_10_signed_checked(input.as_bytes());
+    finish(input, result, ErrorKind::Digit)
+}
+
+/// Parses a hexadecimal integer without sign or `0x` prefix. See [`FromRadix16Checked`].
+pub fn hex_uint<I, T, E>(input: T) -> IResult<T, I, E>
+where
+    I: FromRadix16Checked,
+    T: Input + AsBytes,
+    E: ParseError<T>,
+{
+    let result = I::try_from_radix_16_checked(input.as_bytes());
+    finish(input, result, ErrorKind::HexDigit)
+}
+
+/// Parses a hexadecimal integer with an optional sign, but without `0x` prefix. See
+/// [`FromRadix16SignedChecked`].
+pub fn hex_int<I, T, E>(input: T) -> IResult<T, I, E>
+where
+    I: FromRadix16SignedChecked,
+    T: Input + AsBytes,
+    E: ParseError<T>,
+{
+    let result = I::try_from_radix_16_signed_checked(input.as_bytes());
+    finish(input, result, ErrorKind::HexDigit)
+}
+
+/// Splits off the parsed number from `input`, or translates the parsing error into the error kind
+/// of `nom`.
+fn finish<I, T, E>(
+    input: T,
+    result: Result<(I, usize), ParseIntegerError>,
+    missing_digits: ErrorKind,
+) -> IResult<T, I, E>
+where
+    T: Input,
+    E: ParseError<T>,
+{
+    match result {
+        // Digits and signs are ASCII, so `used` is at a character boundary even for `&str`.
+        Ok((number, used)) => Ok((input.take_from(used), number)),
+        Err(error) => {
+            let kind = match error {
+                ParseIntegerError::Empty
+                | ParseIntegerError::InvalidDigit { .. }
+                | ParseIntegerError::SignOnly => missing_digits,
+                ParseIntegerError::PosOverflow | ParseIntegerError::NegOverflow => {
+                    ErrorKind::TooLarge
+                }
+                ParseIntegerError::Zero => ErrorKind::Verify,
+            };
+            Err(Err::Error(E::from_error_kind(input, kind)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::nom::error::Error;
+
+    use super::*;
+
+    #[test]
+    fn nom_combinators() {
+        assert_eq!(Ok((&b"x"[..], 42u32)), dec_uint::<_, _, ()>(&b"42x"[..]));
+        assert_eq!(Ok(("", -42i64)), dec_int::<_, _, ()>("-42"));
+        assert_eq!(Ok((" ", 0xffu8)), hex_uint::<_, _, ()>("ff "));
+        assert_eq!(Ok(("", -0x80i8)), hex_int::<_, _, ()>("-80"));
+        assert_eq!(
+            Err(Err::Error(Error::new("", ErrorKind::Digit))),
+            dec_uint::<u8, _, _>("")
+        );
+        assert_eq!(
+            Err(Err::Error(Error::new("-42", ErrorKind::Digit))),
+            dec_uint::<u8, _, _>("-42")
+        );
+        assert_eq!(
+            Err(Err::Error(Error::new("-", ErrorKind::Digit))),
+            dec_int::<i8, _, _>("-")
+        );
+        assert_eq!(
+            Err(Err::Error(Error::new("g", ErrorKind::HexDigit))),
+            hex_uint::<u8, _, _>("g")
+        );
+        assert_eq!(
+            Err(Err::Error(Error::new("256", ErrorKind::TooLarge))),
+            dec_uint::<u8, _, _>("256")
+        );
+        assert_eq!(
+            Err(Err::Error(Error::new("-81", ErrorKind::TooLarge))),
+            hex_int::<i8, _, _>("-81")
+        );
+        assert_eq!(
+            Err(Err::Error(Error::new("0", ErrorKind::Verify))),
+            dec_uint::<core::num::NonZeroU8, _, _>("0")
+        );
+    }
+}
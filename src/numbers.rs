@@ -0,0 +1,196 @@
+//! Extracting all integers from a slice, e.g. a log line or a row of a matrix.
+
+use core::{marker::PhantomData, ops::Range};
+
+use crate::{
+    FromRadix10, FromRadix10Checked, FromRadix10Saturating, FromRadix10Signed,
+    FromRadix10SignedChecked, FromRadix10SignedSaturating, OverflowPolicy, Sign,
+};
+
+/// Iterates over all decimal integers in `text`. Each item is the parsed number together with the
+/// range of bytes it has been parsed from. Bytes which are not part of a number are skipped.
+///
+/// By default numbers are unsigned, any byte other than a digit is skipped and overflows are
+/// reported as `None`. Use the builder methods of [`Numbers`] to change this.
+///
+/// # Example
+///
+/// ```
+/// use atoi::numbers;
+///
+/// let mut numbers = numbers::<u8>(b"GET /items/42 took 300ms");
+/// assert_eq!(Some((Some(42), 11..13)), numbers.next());
+/// // Overflow is indicated by `None`
+/// assert_eq!(Some((None, 19..22)), numbers.next());
+/// assert_eq!(None, numbers.next());
+/// ```
+pub fn numbers<I>(text: &(impl AsRef<[u8]> + ?Sized)) -> Numbers<'_, I> {
+    Numbers {
+        text: text.as_ref(),
+        position: 0,
+        signed: false,
+        overflow: OverflowPolicy::Checked,
+        delimiters: None,
+        _integer: PhantomData,
+    }
+}
+
+/// Iterator over the integers in a slice. Created by [`numbers`].
+#[derive(Clone, Debug)]
+pub struct Numbers<'a, I> {
+    text: &'a [u8],
+    /// Index of the first byte, which has not been inspected yet.
+    position: usize,
+    signed: bool,
+    overflow: OverflowPolicy,
+    delimiters: Option<&'a [u8]>,
+    _integer: PhantomData<fn() -> I>,
+}
+
+impl<'a, I> Numbers<'a, I> {
+    /// Whether numbers may start with a sign. A sign is only considered part of a number, if it is
+    /// immediately followed by a digit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::numbers;
+    ///
+    /// let mut numbers = numbers::<i32>(b"x=-3, y=+4, z=-").signed(true);
+    /// assert_eq!(Some((Some(-3), 2..4)), numbers.next());
+    /// assert_eq!(Some((Some(4), 8..10)), numbers.next());
+    /// assert_eq!(None, numbers.next());
+    /// ```
+    pub fn signed(self, signed: bool) -> Self {
+        Numbers { signed, ..self }
+    }
+
+    /// How to deal with numbers which are not representable by the integer type. Only
+    /// [`OverflowPolicy::Checked`] yields `None`.
+    pub fn overflow(self, overflow: OverflowPolicy) -> Self {
+        Numbers { overflow, ..self }
+    }
+
+    /// Only consider sequences of bytes between any of the `delimiters` (or the ends of the slice).
+    /// Sequences, which are not entirely a number are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use atoi::numbers;
+    ///
+    /// let mut numbers = numbers::<u32>(b"1 2\t3x 4\n").delimiters(b" \t\n");
+    /// assert_eq!(Some((Some(1), 0..1)), numbers.next());
+    /// assert_eq!(Some((Some(2), 2..3)), numbers.next());
+    /// assert_eq!(Some((Some(4), 7..8)), numbers.next());
+    /// assert_eq!(None, numbers.next());
+    /// ```
+    pub fn delimiters(self, delimiters: &'a [u8]) -> Self {
+        Numbers {
+            delimiters: Some(delimiters),
+            ..self
+        }
+    }
+
+    /// `true` if `text` starts with a digit, or a sign followed by a digit.
+    fn starts_number(&self, text: &[u8]) -> bool {
+        match text {
+            [first, ..] if first.is_ascii_digit() => true,
+            [sign, digit, ..] if self.signed => {
+                Sign::try_from(*sign).is_some() && digit.is_ascii_digit()
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<I> Numbers<'_, I>
+where
+    I: FromRadix10
+        + FromRadix10Signed
+        + FromRadix10Checked
+        + FromRadix10SignedChecked
+        + FromRadix10Saturating
+        + FromRadix10SignedSaturating,
+{
+    fn parse(&self, text: &[u8]) -> (Option<I>, usize) {
+        let (number, used) = match (self.overflow, self.signed) {
+            (OverflowPolicy::Checked, false) => return I::from_radix_10_checked(text),
+            (OverflowPolicy::Checked, true) => return I::from_radix_10_signed_checked(text),
+            (OverflowPolicy::Saturating, false) => I::from_radix_10_saturating(text),
+            (OverflowPolicy::Saturating, true) => I::from_radix_10_signed_saturating(text),
+            (OverflowPolicy::Unchecked, false) => I::from_radix_10(text),
+            (OverflowPolicy::Unchecked, true) => I::from_radix_10_signed(text),
+        };
+        (Some(number), used)
+    }
+}
+
+impl<I> Iterator for Numbers<'_, I>
+where
+    I: FromRadix10
+        + FromRadix10Signed
+        + FromRadix10Checked
+        + FromRadix10SignedChecked
+        + FromRadix10Saturating
+        + FromRadix10SignedSaturating,
+{
+    type Item = (Option<I>, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let rest = &self.text[self.position..];
+            let Some(delimiters) = self.delimiters else {
+                let offset = (0..rest.len()).find(|&index| self.starts_number(&rest[index..]))?;
+                let start = self.position + offset;
+                let (number, used) = self.parse(&self.text[start..]);
+                self.position = start + used;
+                return Some((number, start..self.position));
+            };
+
+            let start = self.position + rest.iter().position(|byte| !delimiters.contains(byte))?;
+            let end = self.text[start..]
+                .iter()
+                .position(|byte| delimiters.contains(byte))
+                .map_or(self.text.len(), |len| start + len);
+            self.position = end;
+            let field = &self.text[start..end];
+            if self.starts_number(field) {
+                let (number, used) = self.parse(field);
+                if used == field.len() {
+                    return Some((number, start..end));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iterate_numbers() {
+        let mut iter = numbers::<i8>(b"-1 2-3 +-4 5- -200").signed(true);
+        assert_eq!(Some((Some(-1), 0..2)), iter.next());
+        assert_eq!(Some((Some(2), 3..4)), iter.next());
+        assert_eq!(Some((Some(-3), 4..6)), iter.next());
+        assert_eq!(Some((Some(-4), 8..10)), iter.next());
+        assert_eq!(Some((Some(5), 11..12)), iter.next());
+        assert_eq!(Some((None, 14..18)), iter.next());
+        assert_eq!(None, iter.next());
+
+        let mut iter = numbers::<i8>(b"-200,300,-,,1a,12")
+            .signed(true)
+            .overflow(OverflowPolicy::Saturating)
+            .delimiters(b",");
+        assert_eq!(Some((Some(i8::MIN), 0..4)), iter.next());
+        assert_eq!(Some((Some(i8::MAX), 5..8)), iter.next());
+        assert_eq!(Some((Some(12), 15..17)), iter.next());
+        assert_eq!(None, iter.next());
+
+        assert_eq!(0, numbers::<u32>(b"").count());
+        assert_eq!(0, numbers::<u32>(b"-").signed(true).count());
+        assert_eq!(0, numbers::<u32>(b",,,").delimiters(b",").count());
+    }
+}
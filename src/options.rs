@@ -0,0 +1,419 @@
+//! A single entry point for parsing integers, configured at runtime or compile time.
+
+use crate::{
+    FromRadix, FromRadix10, FromRadix10Checked, FromRadix10Saturating, FromRadix10Signed,
+    FromRadix10SignedChecked, FromRadix10SignedSaturating, FromRadix16, FromRadix16Checked,
+    FromRadix16Saturating, FromRadix16Signed, FromRadix16SignedChecked,
+    FromRadix16SignedSaturating, FromRadixChecked, FromRadixSaturating, FromRadixSigned,
+    FromRadixSignedChecked, FromRadixSignedSaturating, ParseIntegerError, Separator, Sign,
+    error::{signed_result, unsigned_result},
+    separator::parse_separated,
+    whitespace::{check_trailing_whitespace, leading_whitespace},
+};
+
+/// How to deal with numbers too large or too small for the integer type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Report overflows as [`ParseIntegerError::PosOverflow`] or
+    /// [`ParseIntegerError::NegOverflow`]. Like [`FromRadix10Checked`].
+    Checked,
+    /// Do not check for overflows. Like [`FromRadix10`], the result of an overflow is unspecified
+    /// and may panic in debug builds.
+    Unchecked,
+    /// Clamp numbers out of range to the minimum or maximum of the integer type. Like
+    /// [`crate::FromRadix10SignedSaturating`].
+    Saturating,
+}
+
+/// How to deal with bytes following the number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrailingPolicy {
+    /// Stop parsing at the first byte, which does not belong to the number.
+    Ignore,
+    /// Report any trailing bytes as [`ParseIntegerError::InvalidDigit`].
+    Reject,
+    /// Report any trailing bytes other than whitespace as [`ParseIntegerError::InvalidDigit`].
+    /// See [`crate::Whitespace`] for which bytes count as whitespace.
+    Whitespace,
+}
+
+/// Describes how [`parse`] interprets a slice. Construct it with [`ParseOptions::new`] and adjust
+/// it using the builder methods. All methods are `const`, so options can be defined once as a
+/// constant.
+///
+/// # Example
+///
+/// ```
+/// use atoi::{parse, ParseIntegerError, ParseOptions, TrailingPolicy};
+///
+/// const HEX: ParseOptions = ParseOptions::new()
+///     .radix(16)
+///     .allow_sign(false)
+///     .trailing(TrailingPolicy::Reject);
+///
+/// assert_eq!(Ok((0xff, 2)), parse::<u8>(&HEX, b"ff"));
+/// assert_eq!(Err(ParseIntegerError::InvalidDigit { position: 0 }), parse::<i8>(&HEX, b"-1"));
+/// assert_eq!(Err(ParseIntegerError::PosOverflow), parse::<u8>(&HEX, b"100"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseOptions {
+    radix: u32,
+    allow_sign: bool,
+    allow_plus: bool,
+    overflow: OverflowPolicy,
+    trailing: TrailingPolicy,
+    skip_leading_whitespace: bool,
+    separator: Option<Separator>,
+}
+
+impl ParseOptions {
+    /// Options parsing decimal numbers with an optional leading sign, like [`crate::try_atoi`].
+    /// Overflows are checked, trailing bytes are ignored, leading whitespace and separators are
+    /// not allowed.
+    pub const fn new() -> Self {
+        ParseOptions {
+            radix: 10,
+            allow_sign: true,
+            allow_plus: true,
+            overflow: OverflowPolicy::Checked,
+            trailing: TrailingPolicy::Ignore,
+            skip_leading_whitespace: false,
+            separator: None,
+        }
+    }
+
+    /// Radix of the positional numeral system. Digits above nine are case insensitive.
+    ///
+    /// # Panics
+    ///
+    /// If `radix` is not in the range from 2 to 36.
+    pub const fn radix(self, radix: u32) -> Self {
+        assert!(
+            2 <= radix && radix <= 36,
+            "radix must be in the range from 2 to 36"
+        );
+        ParseOptions { radix, ..self }
+    }
+
+    /// Whether the number may start with a sign. If `false` a leading sign is reported as
+    /// [`ParseIntegerError::InvalidDigit`].
+    pub const fn allow_sign(self, allow_sign: bool) -> Self {
+        ParseOptions { allow_sign, ..self }
+    }
+
+    /// Whether the number may start with an explicit `+`. Has no effect if signs are not allowed
+    /// at all.
+    pub const fn allow_plus(self, allow_plus: bool) -> Self {
+        ParseOptions { allow_plus, ..self }
+    }
+
+    /// How to deal with numbers which are not representable by the integer type.
+    pub const fn overflow(self, overflow: OverflowPolicy) -> Self {
+        ParseOptions { overflow, ..self }
+    }
+
+    /// How to deal with bytes following the number.
+    pub const fn trailing(self, trailing: TrailingPolicy) -> Self {
+        ParseOptions { trailing, ..self }
+    }
+
+    /// Whether to skip whitespace in front of the number (and its sign). See
+    /// [`crate::Whitespace`] for which bytes count as whitespace.
+    pub const fn skip_leading_whitespace(self, skip_leading_whitespace: bool) -> Self {
+        ParseOptions {
+            skip_leading_whitespace,
+            ..self
+        }
+    }
+
+    /// Allow the digits to be split by `separator`.
+    pub const fn separator(self, separator: Separator) -> Self {
+        ParseOptions {
+            separator: Some(separator),
+            ..self
+        }
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions::new()
+    }
+}
+
+/// Integer types which can be parsed using [`parse`]. Implemented for every type implementing the
+/// required parsing traits, which includes all builtin integer types.
+pub trait FromParseOptions:
+    FromRadix10
+    + FromRadix10Checked
+    + FromRadix10Signed
+    + FromRadix10SignedChecked
+    + FromRadix10Saturating
+    + FromRadix10SignedSaturating
+    + FromRadix16
+    + FromRadix16Checked
+    + FromRadix16Signed
+    + FromRadix16SignedChecked
+    + FromRadix16Saturating
+    + FromRadix16SignedSaturating
+    + FromRadix
+    + FromRadixChecked
+    + FromRadixSigned
+    + FromRadixSignedChecked
+    + FromRadixSaturating
+    + FromRadixSignedSaturating
+{
+}
+
+impl<I> FromParseOptions for I where
+    I: FromRadix10
+        + FromRadix10Checked
+        + FromRadix10Signed
+        + FromRadix10SignedChecked
+        + FromRadix10Saturating
+        + FromRadix10SignedSaturating
+        + FromRadix16
+        + FromRadix16Checked
+        + FromRadix16Signed
+        + FromRadix16SignedChecked
+        + FromRadix16Saturating
+        + FromRadix16SignedSaturating
+        + FromRadix
+        + FromRadixChecked
+        + FromRadixSigned
+        + FromRadixSignedChecked
+        + FromRadixSaturating
+        + FromRadixSignedSaturating
+{
+}
+
+/// Parses an integer from a slice as described by `options`.
+///
+/// If `options` is known at compile time, the checks of the options are optimized away and the
+/// same code as for the dedicated traits like [`FromRadix10Checked`] remains.
+///
+/// # Example
+///
+/// ```
+/// use atoi::{parse, ParseIntegerError, ParseOptions, Separator};
+///
+/// let options = ParseOptions::new()
+///     .skip_leading_whitespace(true)
+///     .separator(Separator::new('_'));
+/// assert_eq!(Ok((-1_000, 8)), parse::<i32>(&options, b"  -1_000 apples"));
+/// assert_eq!(Err(ParseIntegerError::SignOnly), parse::<i32>(&options, b"  -"));
+/// assert_eq!(Err(ParseIntegerError::Empty), parse::<i32>(&options, b"  "));
+///
+/// let options = ParseOptions::new().allow_plus(false);
+/// assert_eq!(
+///     Err(ParseIntegerError::InvalidDigit { position: 0 }),
+///     parse::<i32>(&options, b"+1")
+/// );
+/// ```
+///
+/// # Return
+///
+/// The parsed number and the index of the byte right after it.
+#[inline]
+pub fn parse<I>(
+    options: &ParseOptions,
+    text: impl AsRef<[u8]>,
+) -> Result<(I, usize), ParseIntegerError>
+where
+    I: FromParseOptions,
+{
+    let text = text.as_ref();
+    let start = if options.skip_leading_whitespace {
+        leading_whitespace(text)
+    } else {
+        0
+    };
+    let rest = &text[start..];
+    let signed = match rest.first().and_then(|&byte| Sign::try_from(byte)) {
+        None => false,
+        Some(Sign::Minus) if options.allow_sign => true,
+        Some(Sign::Plus) if options.allow_sign && options.allow_plus => true,
+        Some(_) => return Err(ParseIntegerError::InvalidDigit { position: start }),
+    };
+
+    let radix = options.radix;
+    let separator = options.separator;
+    // Without a sign we can use the faster unsigned parsing even for signed integers.
+    let result = match (options.overflow, signed) {
+        (OverflowPolicy::Checked, false) => match radix {
+            10 => separated(rest, 10, false, separator, I::from_radix_10_checked),
+            16 => separated(rest, 16, false, separator, I::from_radix_16_checked),
+            _ => separated(rest, radix, false, separator, |text| {
+                I::from_radix_checked(text, radix)
+            }),
+        },
+        (OverflowPolicy::Checked, true) => match radix {
+            10 => separated(rest, 10, true, separator, I::from_radix_10_signed_checked),
+            16 => separated(rest, 16, true, separator, I::from_radix_16_signed_checked),
+            _ => separated(rest, radix, true, separator, |text| {
+                I::from_radix_signed_checked(text, radix)
+            }),
+        },
+        (OverflowPolicy::Saturating, false) => some(match radix {
+            10 => separated(rest, 10, false, separator, I::from_radix_10_saturating),
+            16 => separated(rest, 16, false, separator, I::from_radix_16_saturating),
+            _ => separated(rest, radix, false, separator, |text| {
+                I::from_radix_saturating(text, radix)
+            }),
+        }),
+        (OverflowPolicy::Saturating, true) => some(match radix {
+            10 => separated(
+                rest,
+                10,
+                true,
+                separator,
+                I::from_radix_10_signed_saturating,
+            ),
+            16 => separated(
+                rest,
+                16,
+                true,
+                separator,
+                I::from_radix_16_signed_saturating,
+            ),
+            _ => separated(rest, radix, true, separator, |text| {
+                I::from_radix_signed_saturating(text, radix)
+            }),
+        }),
+        (OverflowPolicy::Unchecked, false) => some(match radix {
+            10 => separated(rest, 10, false, separator, I::from_radix_10),
+            16 => separated(rest, 16, false, separator, I::from_radix_16),
+            _ => separated(rest, radix, false, separator, |text| {
+                I::from_radix(text, radix)
+            }),
+        }),
+        (OverflowPolicy::Unchecked, true) => some(match radix {
+            10 => separated(rest, 10, true, separator, I::from_radix_10_signed),
+            16 => separated(rest, 16, true, separator, I::from_radix_16_signed),
+            _ => separated(rest, radix, true, separator, |text| {
+                I::from_radix_signed(text, radix)
+            }),
+        }),
+    };
+    let (number, used) = if signed {
+        signed_result(rest, result)
+    } else {
+        unsigned_result(rest, result)
+    }
+    .map_err(|error| error.shifted(start))?;
+
+    let end = start + used;
+    if options.trailing == TrailingPolicy::Reject && end != text.len() {
+        return Err(ParseIntegerError::InvalidDigit { position: end });
+    }
+    if options.trailing == TrailingPolicy::Whitespace {
+        check_trailing_whitespace(text, end)?;
+    }
+    Ok((number, end))
+}
+
+/// Parses `text` using `parse`, allowing its digits to be split by `separator` if there is one.
+#[inline]
+fn separated<T>(
+    text: &[u8],
+    radix: u32,
+    signed: bool,
+    separator: Option<Separator>,
+    parse: impl Fn(&[u8]) -> (T, usize),
+) -> (T, usize) {
+    match separator {
+        Some(separator) => parse_separated(text, radix, signed, separator, parse),
+        None => parse(text),
+    }
+}
+
+/// Wraps the result of a saturating or unchecked trait, so it can be treated like the result of a
+/// checked one.
+#[inline]
+fn some<I>((number, used): (I, usize)) -> (Option<I>, usize) {
+    (Some(number), used)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_with_options() {
+        let options = ParseOptions::new();
+        assert_eq!(Ok((-128, 4)), parse::<i8>(&options, b"-128"));
+        assert_eq!(Ok((127, 4)), parse::<i8>(&options, b"+127"));
+        assert_eq!(
+            Err(ParseIntegerError::NegOverflow),
+            parse::<i8>(&options, b"-129")
+        );
+        assert_eq!(
+            Err(ParseIntegerError::NegOverflow),
+            parse::<u8>(&options, b"-1")
+        );
+        assert_eq!(Ok((0, 2)), parse::<u8>(&options, b"-0"));
+        assert_eq!(
+            Err(ParseIntegerError::InvalidDigit { position: 0 }),
+            parse::<u8>(&options, b" 1")
+        );
+
+        let options = ParseOptions::new()
+            .radix(2)
+            .skip_leading_whitespace(true)
+            .trailing(TrailingPolicy::Reject);
+        assert_eq!(Ok((-5, 5)), parse::<i32>(&options, b"\t-101"));
+        assert_eq!(
+            Err(ParseIntegerError::InvalidDigit { position: 4 }),
+            parse::<i32>(&options, b" -102")
+        );
+        assert_eq!(
+            Err(ParseIntegerError::InvalidDigit { position: 2 }),
+            parse::<i32>(&options, b" -x")
+        );
+
+        let options = ParseOptions::new()
+            .radix(16)
+            .overflow(OverflowPolicy::Unchecked);
+        assert_eq!(Ok((-0x80, 3)), parse::<i8>(&options, b"-80"));
+        assert_eq!(Ok((0xff, 2)), parse::<u8>(&options, b"ff"));
+
+        let options = ParseOptions::new()
+            .overflow(OverflowPolicy::Unchecked)
+            .separator(Separator::new(',').grouped(3));
+        assert_eq!(Ok((-1_000, 6)), parse::<i16>(&options, b"-1,000,00"));
+        assert_eq!(Ok((100_000, 7)), parse::<u32>(&options, b"100,000"));
+        let options = options.radix(16);
+        assert_eq!(Ok((-0x1000, 6)), parse::<i16>(&options, b"-1,000"));
+    }
+
+    #[test]
+    fn saturating_parsing_with_options() {
+        let options = ParseOptions::new()
+            .radix(8)
+            .overflow(OverflowPolicy::Saturating);
+        assert_eq!(Ok((i8::MIN, 5)), parse::<i8>(&options, b"-1000"));
+        assert_eq!(Ok((i8::MAX, 4)), parse::<i8>(&options, b"1000"));
+        assert_eq!(Ok((u8::MIN, 2)), parse::<u8>(&options, b"-1"));
+        let options = options.separator(Separator::new('_'));
+        assert_eq!(Ok((u8::MAX, 5)), parse::<u8>(&options, b"1_000"));
+        // More digits than are passed on to the saturating traits
+        let mut nines = [b'9'; 202];
+        nines[0] = b'-';
+        nines[2] = b'_';
+        let options = ParseOptions::new()
+            .overflow(OverflowPolicy::Saturating)
+            .separator(Separator::new('_'));
+        assert_eq!(Ok((i128::MIN, 202)), parse::<i128>(&options, &nines));
+        assert_eq!(Ok((u128::MAX, 201)), parse::<u128>(&options, &nines[1..]));
+        let options = ParseOptions::new().overflow(OverflowPolicy::Saturating);
+        assert_eq!(Ok((i8::MIN, 5)), parse::<i8>(&options, b"-1000"));
+        assert_eq!(Ok((u8::MAX, 4)), parse::<u8>(&options, b"1000"));
+        assert_eq!(
+            Err(ParseIntegerError::SignOnly),
+            parse::<i8>(&options, b"-")
+        );
+        let options = options.radix(16);
+        assert_eq!(Ok((u8::MAX, 3)), parse::<u8>(&options, b"100"));
+        assert_eq!(Ok((i8::MIN, 4)), parse::<i8>(&options, b"-100"));
+    }
+}
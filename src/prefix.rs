@@ -0,0 +1,167 @@
+//! Parsing numbers whose radix is indicated by a prefix, like `0x1F`, `0o755` or `0b1010`.
+
+use crate::{FromRadix10SignedChecked, FromRadix16SignedChecked, FromRadixSignedChecked, Sign};
+
+/// Conventions for indicating the radix of a number using a prefix. Numbers without a prefix are
+/// decimal in either style.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrefixStyle {
+    /// `0x` for hexadecimal, `0o` for octal and `0b` for binary numbers. A leading zero has no
+    /// special meaning.
+    Rust,
+    /// `0x` for hexadecimal and `0b` for binary numbers. Any number starting with a zero followed
+    /// by another digit is octal, e.g. `0755`.
+    C,
+}
+
+impl PrefixStyle {
+    /// Detects the radix of the number at the start of `text`. Returns the radix and the length of
+    /// the prefix.
+    fn detect(self, text: &[u8]) -> (u32, usize) {
+        let radix = match (self, text) {
+            (_, [b'0', b'x' | b'X', ..]) => 16,
+            (_, [b'0', b'b' | b'B', ..]) => 2,
+            (PrefixStyle::Rust, [b'0', b'o' | b'O', ..]) => 8,
+            (PrefixStyle::C, [b'0', digit, ..]) if digit.is_ascii_digit() => return (8, 1),
+            _ => return (10, 0),
+        };
+        // A prefix without any digit following it, is just a zero.
+        if text
+            .get(2)
+            .is_some_and(|&byte| (byte as char).is_digit(radix))
+        {
+            (radix, 2)
+        } else {
+            (10, 0)
+        }
+    }
+}
+
+/// Parses an integer with an optional leading sign from a slice, detecting its radix from a
+/// prefix following the sign.
+///
+/// Returns the number, the detected radix and the number of bytes used, including sign and
+/// prefix. `None` is returned in case of overflow. A prefix not followed by any valid digit is not
+/// consumed, in which case the number is a decimal zero.
+///
+/// # Example
+///
+/// ```
+/// use atoi::{atoi_auto, PrefixStyle};
+/// assert_eq!((Some(31), 16, 4), atoi_auto::<i32>(b"0x1F", PrefixStyle::Rust));
+/// assert_eq!((Some(-493), 8, 6), atoi_auto::<i32>(b"-0o755", PrefixStyle::Rust));
+/// assert_eq!((Some(10), 2, 6), atoi_auto::<i32>(b"0b1010", PrefixStyle::Rust));
+/// assert_eq!((Some(755), 10, 4), atoi_auto::<i32>(b"0755", PrefixStyle::Rust));
+/// assert_eq!((Some(493), 8, 4), atoi_auto::<i32>(b"0755", PrefixStyle::C));
+/// assert_eq!((Some(0), 10, 1), atoi_auto::<i32>(b"0x", PrefixStyle::C));
+/// assert_eq!((Some(i8::MIN), 16, 5), atoi_auto::<i8>(b"-0x80", PrefixStyle::C));
+/// assert_eq!((None, 16, 5), atoi_auto::<u8>(b"0x100", PrefixStyle::C));
+/// ```
+pub fn atoi_auto<I>(text: impl AsRef<[u8]>, style: PrefixStyle) -> (Option<I>, u32, usize)
+where
+    I: FromRadix10SignedChecked + FromRadix16SignedChecked + FromRadixSignedChecked,
+{
+    let text = text.as_ref();
+    let sign = text.first().and_then(|&byte| Sign::try_from(byte));
+    let offset = if sign.is_some() { 1 } else { 0 };
+    let (radix, prefix_len) = style.detect(&text[offset..]);
+    // The leading zero of a C style octal number is a valid digit, so it can stay in place.
+    if prefix_len < 2 {
+        let (number, used) = from_radix_signed_checked(text, radix);
+        return (number, radix, used);
+    }
+    let start = offset + prefix_len;
+    let digits = &text[start..];
+    let (number, used) = match sign {
+        Some(Sign::Minus) => from_radix_negative(digits, radix),
+        _ => from_radix_signed_checked(digits, radix),
+    };
+    (number, radix, start + used)
+}
+
+/// Dispatches to the fastest signed checked parsing trait for `radix`.
+fn from_radix_signed_checked<I>(text: &[u8], radix: u32) -> (Option<I>, usize)
+where
+    I: FromRadix10SignedChecked + FromRadix16SignedChecked + FromRadixSignedChecked,
+{
+    match radix {
+        10 => I::from_radix_10_signed_checked(text),
+        16 => I::from_radix_16_signed_checked(text),
+        _ => I::from_radix_signed_checked(text, radix),
+    }
+}
+
+/// Maximum number of significant digits of a negative number with a prefix. Enough for any integer
+/// of up to 128 bits in any radix.
+const MAX_DIGITS: usize = 128;
+
+/// The prefix separates the minus sign from the digits. So the significant digits are copied right
+/// behind a minus sign, which allows the signed parsing traits to handle the smallest number, whose
+/// magnitude may not be representable as a positive number. Numbers with more than [`MAX_DIGITS`]
+/// significant digits are reported as overflow.
+fn from_radix_negative<I>(digits: &[u8], radix: u32) -> (Option<I>, usize)
+where
+    I: FromRadix10SignedChecked + FromRadix16SignedChecked + FromRadixSignedChecked,
+{
+    let used = digits
+        .iter()
+        .take_while(|&&byte| (byte as char).is_digit(radix))
+        .count();
+    let significant = digits[..used]
+        .iter()
+        .position(|&byte| byte != b'0')
+        .map_or(&b"0"[..], |first| &digits[first..used]);
+    if significant.len() > MAX_DIGITS {
+        return (None, used);
+    }
+    let mut buffer = [b'-'; 1 + MAX_DIGITS];
+    buffer[1..=significant.len()].copy_from_slice(significant);
+    let (number, _) = from_radix_signed_checked(&buffer[..=significant.len()], radix);
+    (number, used)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Integer;
+
+    #[test]
+    fn radix_auto_detection() {
+        assert_eq!(
+            (Some(-31), 16, 5),
+            atoi_auto::<i64>(b"-0X1f", PrefixStyle::Rust)
+        );
+        assert_eq!(
+            (Some(5), 2, 6),
+            atoi_auto::<i64>(b"+0b101", PrefixStyle::Rust)
+        );
+        assert_eq!(
+            (Some(0), 10, 1),
+            atoi_auto::<i64>(b"0o8", PrefixStyle::Rust)
+        );
+        assert_eq!((Some(0), 10, 1), atoi_auto::<i64>(b"0o", PrefixStyle::C));
+        assert_eq!((Some(0), 8, 1), atoi_auto::<i64>(b"08", PrefixStyle::C));
+        assert_eq!((Some(-5), 8, 3), atoi_auto::<i64>(b"-05", PrefixStyle::C));
+        assert_eq!((Some(0), 10, 1), atoi_auto::<i64>(b"0", PrefixStyle::C));
+        assert_eq!((Some(-42), 10, 3), atoi_auto::<i64>(b"-42", PrefixStyle::C));
+        assert_eq!((Some(0), 16, 4), atoi_auto::<u8>(b"-0x0", PrefixStyle::C));
+        assert_eq!((None, 16, 4), atoi_auto::<u8>(b"-0x1", PrefixStyle::C));
+        assert_eq!((None, 16, 5), atoi_auto::<i8>(b"-0x81", PrefixStyle::C));
+        assert_eq!((None, 16, 4), atoi_auto::<i8>(b"0x80", PrefixStyle::C));
+        assert_eq!(
+            (Some(i128::MIN), 2, 131),
+            atoi_auto::<i128>(
+                b"-0b10000000000000000000000000000000000000000000000000000000000000000\
+                000000000000000000000000000000000000000000000000000000000000000",
+                PrefixStyle::Rust
+            )
+        );
+        // Leading zeros do not count towards the digits of a negative number
+        let mut zeros = [b'0'; 204];
+        zeros[..3].copy_from_slice(b"-0x");
+        zeros[203] = b'1';
+        assert_eq!((Some(-1), 16, 204), atoi_auto::<i8>(&zeros, PrefixStyle::C));
+        let (number, radix, used) = atoi_auto::<Integer<i8>>(b"-0x80", PrefixStyle::Rust);
+        assert_eq!((Some(i8::MIN), 16, 5), (number.map(|n| n.0), radix, used));
+    }
+}
@@ -0,0 +1,254 @@
+//! Parsing lines of delimiter separated integers, like rows of a CSV or TSV file.
+
+use core::fmt::{self, Display, Formatter};
+
+use crate::{FromRadix10SignedChecked, ParseIntegerError, parse_exact};
+
+/// Describes which field of a row could not be parsed and why.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FieldError {
+    /// Zero based index of the field within the row.
+    pub field: usize,
+    /// Index of the first byte of the field within the row.
+    pub start: usize,
+    /// Why the field could not be parsed. Positions are relative to `start`.
+    pub error: ParseIntegerError,
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "field {} starting at byte {}: {}",
+            self.field, self.start, self.error
+        )
+    }
+}
+
+impl core::error::Error for FieldError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Rows of delimiter separated integers. Implemented for tuples of up to twelve types
+/// implementing [`FromRadix10SignedChecked`].
+pub trait FromRow: Sized {
+    /// Parses one field for each element of the row. See [`parse_row`].
+    fn from_row(line: &[u8], delimiter: u8) -> Result<Self, FieldError>;
+}
+
+/// Parses a line of decimal integers separated by `delimiter` into a tuple.
+///
+/// Each field must consist of a number with an optional sign and nothing else. The last field
+/// spans the remainder of the line, so any additional fields are reported as an invalid digit of
+/// the last one. Strip line breaks (e.g. `\r\n`) before calling this function.
+///
+/// # Example
+///
+/// ```
+/// use atoi::{parse_row, FieldError, ParseIntegerError};
+///
+/// assert_eq!(Ok((1, -2, 3)), parse_row::<(u32, i64, u16)>(b"1,-2,3", b','));
+/// assert_eq!(
+///     Err(FieldError { field: 1, start: 2, error: ParseIntegerError::InvalidDigit { position: 1 } }),
+///     parse_row::<(u32, i64, u16)>(b"1,2x,3", b',')
+/// );
+/// // Missing fields are empty
+/// assert_eq!(
+///     Err(FieldError { field: 2, start: 4, error: ParseIntegerError::Empty }),
+///     parse_row::<(u32, i64, u16)>(b"1\t2\t", b'\t')
+/// );
+/// ```
+#[inline]
+pub fn parse_row<R>(line: impl AsRef<[u8]>, delimiter: u8) -> Result<R, FieldError>
+where
+    R: FromRow,
+{
+    R::from_row(line.as_ref(), delimiter)
+}
+
+/// Parses a line of decimal integers separated by `delimiter`, into a caller provided slice. The
+/// line must contain exactly one field for each element of `row`. If `row` is empty, so must be
+/// the line, or its first byte is reported as an invalid digit of field zero.
+///
+/// Otherwise identical to [`parse_row`].
+///
+/// # Example
+///
+/// ```
+/// use atoi::{parse_row_into, FieldError, ParseIntegerError};
+///
+/// let mut row = [0i32; 4];
+/// assert_eq!(Ok(()), parse_row_into(b"4;-3;2;1", b';', &mut row));
+/// assert_eq!([4, -3, 2, 1], row);
+/// assert_eq!(
+///     Err(FieldError { field: 3, start: 6, error: ParseIntegerError::InvalidDigit { position: 1 } }),
+///     parse_row_into(b"4;3;2;1;0", b';', &mut row)
+/// );
+/// ```
+#[inline]
+pub fn parse_row_into<I>(
+    line: impl AsRef<[u8]>,
+    delimiter: u8,
+    row: &mut [I],
+) -> Result<(), FieldError>
+where
+    I: FromRadix10SignedChecked,
+{
+    let line = line.as_ref();
+    // Without any elements, there is no last field to report additional bytes for.
+    if row.is_empty() && !line.is_empty() {
+        return Err(FieldError {
+            field: 0,
+            start: 0,
+            error: ParseIntegerError::InvalidDigit { position: 0 },
+        });
+    }
+    let mut fields = Fields::new(line, delimiter, row.len());
+    for element in row {
+        *element = fields.parse()?;
+    }
+    Ok(())
+}
+
+/// Splits a line into a known number of fields.
+struct Fields<'a> {
+    line: &'a [u8],
+    delimiter: u8,
+    num_fields: usize,
+    /// Index of the next field.
+    field: usize,
+    /// Start of the next field. `None` once the end of the line has been reached.
+    start: Option<usize>,
+}
+
+impl<'a> Fields<'a> {
+    fn new(line: &'a [u8], delimiter: u8, num_fields: usize) -> Self {
+        Fields {
+            line,
+            delimiter,
+            num_fields,
+            field: 0,
+            start: Some(0),
+        }
+    }
+
+    fn parse<I>(&mut self) -> Result<I, FieldError>
+    where
+        I: FromRadix10SignedChecked,
+    {
+        let field = self.field;
+        self.field += 1;
+        let Some(start) = self.start else {
+            return Err(FieldError {
+                field,
+                start: self.line.len(),
+                error: ParseIntegerError::Empty,
+            });
+        };
+        let end = if self.field == self.num_fields {
+            self.line.len()
+        } else {
+            self.line[start..]
+                .iter()
+                .position(|&byte| byte == self.delimiter)
+                .map_or(self.line.len(), |len| start + len)
+        };
+        self.start = (end != self.line.len()).then_some(end + 1);
+        parse_exact(&self.line[start..end]).map_err(|error| FieldError {
+            field,
+            start,
+            error,
+        })
+    }
+}
+
+macro_rules! impl_from_row {
+    ($($t:ident),+) => {
+        impl<$($t),+> FromRow for ($($t,)+)
+        where
+            $($t: FromRadix10SignedChecked),+
+        {
+            #[inline]
+            fn from_row(line: &[u8], delimiter: u8) -> Result<Self, FieldError> {
+                let num_fields = [$(stringify!($t)),+].len();
+                let mut fields = Fields::new(line, delimiter, num_fields);
+                Ok(($(fields.parse::<$t>()?,)+))
+            }
+        }
+    };
+}
+
+impl_from_row!(A);
+impl_from_row!(A, B);
+impl_from_row!(A, B, C);
+impl_from_row!(A, B, C, D);
+impl_from_row!(A, B, C, D, E);
+impl_from_row!(A, B, C, D, E, F);
+impl_from_row!(A, B, C, D, E, F, G);
+impl_from_row!(A, B, C, D, E, F, G, H);
+impl_from_row!(A, B, C, D, E, F, G, H, I);
+impl_from_row!(A, B, C, D, E, F, G, H, I, J);
+impl_from_row!(A, B, C, D, E, F, G, H, I, J, K);
+impl_from_row!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn row_parsing() {
+        assert_eq!(Ok((-7,)), parse_row::<(i8,)>(b"-7", b','));
+        assert_eq!(
+            Ok((1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12)),
+            parse_row::<(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8)>(
+                b"1,2,3,4,5,6,7,8,9,10,11,12",
+                b','
+            )
+        );
+        assert_eq!(
+            Err(FieldError {
+                field: 1,
+                start: 2,
+                error: ParseIntegerError::Empty
+            }),
+            parse_row::<(u8, u8, u8)>(b"1,,3", b',')
+        );
+        assert_eq!(
+            Err(FieldError {
+                field: 2,
+                start: 3,
+                error: ParseIntegerError::Empty
+            }),
+            parse_row::<(u8, u8, u8)>(b"1,2", b',')
+        );
+        assert_eq!(
+            Err(FieldError {
+                field: 0,
+                start: 0,
+                error: ParseIntegerError::PosOverflow
+            }),
+            parse_row::<(u8, u8)>(b"256,0", b',')
+        );
+
+        let mut row = [0u64; 2];
+        assert_eq!(
+            Err(FieldError {
+                field: 0,
+                start: 0,
+                error: ParseIntegerError::NegOverflow
+            }),
+            parse_row_into(b"-1\t1", b'\t', &mut row)
+        );
+        assert_eq!(Ok(()), parse_row_into::<u8>(b"", b'\t', &mut []));
+        assert_eq!(
+            Err(FieldError {
+                field: 0,
+                start: 0,
+                error: ParseIntegerError::InvalidDigit { position: 0 }
+            }),
+            parse_row_into::<u8>(b"1", b'\t', &mut [])
+        );
+    }
+}
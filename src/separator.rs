@@ -0,0 +1,346 @@
+//! Parsing numbers whose digits are split into groups, like `1_000_000` or `1,234,567`.
+
+use crate::{
+    FromRadix10Checked, FromRadix10SignedChecked, FromRadix16Checked, FromRadix16SignedChecked,
+    Sign,
+};
+
+/// Describes the separator which may occur between the digits of a number.
+///
+/// By default a separator may occur between any two digits. Use [`Separator::grouped`] to only
+/// accept separators which split the digits into groups of a fixed size, e.g. thousands.
+///
+/// # Example
+///
+/// ```
+/// use atoi::{from_radix_10_separated, Separator};
+///
+/// let underscore = Separator::new('_');
+/// assert_eq!((Some(1_000_000u32), 9), from_radix_10_separated(b"1_000_000", underscore));
+///
+/// let thousands = Separator::new(',').grouped(3);
+/// assert_eq!((Some(1_234_567u32), 9), from_radix_10_separated(b"1,234,567", thousands));
+/// // The last group is too short, so parsing stops in front of its separator
+/// assert_eq!((Some(1_234u32), 5), from_radix_10_separated(b"1,234,56", thousands));
+///
+/// // Separators may be any character, e.g. a thin space
+/// let thin_space = Separator::new('\u{2009}');
+/// assert_eq!(
+///     (Some(1_000u32), 7),
+///     from_radix_10_separated("1\u{2009}000", thin_space)
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Separator {
+    /// UTF-8 encoding of the separator. Only the first `len` bytes are used.
+    bytes: [u8; 4],
+    len: u8,
+    /// Number of digits in each group. `0` if the digits may be grouped arbitrarily.
+    group_size: usize,
+}
+
+impl Separator {
+    /// A separator, which may occur between any two digits.
+    pub const fn new(separator: char) -> Self {
+        let mut bytes = [0; 4];
+        let len = separator.encode_utf8(&mut bytes).len() as u8;
+        Separator {
+            bytes,
+            len,
+            group_size: 0,
+        }
+    }
+
+    /// Only accept the separator if it splits the digits into groups of `group_size` digits. The
+    /// first group may be shorter. Parsing stops in front of any separator violating the
+    /// grouping.
+    ///
+    /// # Panics
+    ///
+    /// If `group_size` is zero.
+    pub const fn grouped(self, group_size: usize) -> Self {
+        assert!(group_size != 0, "group size must not be zero");
+        Separator { group_size, ..self }
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len as usize]
+    }
+
+    /// Index of the first digit following the separator at `index`, if the separator may be
+    /// consumed after a group of `group_len` digits.
+    fn skip(
+        &self,
+        text: &[u8],
+        index: usize,
+        radix: u32,
+        group_len: usize,
+        first_group: bool,
+    ) -> Option<usize> {
+        if group_len == 0 || !text[index..].starts_with(self.as_bytes()) {
+            return None;
+        }
+        let next = index + self.as_bytes().len();
+        let is_digit = |index: usize| {
+            text.get(index)
+                .is_some_and(|&byte| (byte as char).is_digit(radix))
+        };
+        let valid = if self.group_size == 0 {
+            is_digit(next)
+        } else {
+            (group_len == self.group_size || first_group && group_len < self.group_size)
+                && (next..next + self.group_size).all(is_digit)
+                && !is_digit(next + self.group_size)
+        };
+        valid.then_some(next)
+    }
+}
+
+/// Parses an unsigned decimal number from a slice, whose digits may be split by `separator`.
+///
+/// Acts like [`FromRadix10Checked::from_radix_10_checked_separated`].
+///
+/// # Example
+///
+/// ```
+/// use atoi::{from_radix_10_separated, Separator};
+/// assert_eq!(
+///     (Some(1_000u16), 5),
+///     from_radix_10_separated(b"1_000_", Separator::new('_'))
+/// );
+/// assert_eq!((None::<u8>, 5), from_radix_10_separated(b"1_000", Separator::new('_')));
+/// ```
+pub fn from_radix_10_separated<I>(
+    text: impl AsRef<[u8]>,
+    separator: Separator,
+) -> (Option<I>, usize)
+where
+    I: FromRadix10Checked,
+{
+    I::from_radix_10_checked_separated(text.as_ref(), separator)
+}
+
+/// Parses a decimal number with an optional leading sign from a slice, whose digits may be split
+/// by `separator`.
+///
+/// Acts like [`FromRadix10SignedChecked::from_radix_10_signed_checked_separated`].
+///
+/// # Example
+///
+/// ```
+/// use atoi::{from_radix_10_signed_separated, Separator};
+/// let thousands = Separator::new(',').grouped(3);
+/// assert_eq!(
+///     (Some(-1_234_567i32), 10),
+///     from_radix_10_signed_separated(b"-1,234,567", thousands)
+/// );
+/// ```
+pub fn from_radix_10_signed_separated<I>(
+    text: impl AsRef<[u8]>,
+    separator: Separator,
+) -> (Option<I>, usize)
+where
+    I: FromRadix10SignedChecked,
+{
+    I::from_radix_10_signed_checked_separated(text.as_ref(), separator)
+}
+
+/// Parses an unsigned hexadecimal number from a slice, whose digits may be split by `separator`.
+///
+/// Acts like [`FromRadix16Checked::from_radix_16_checked_separated`].
+///
+/// # Example
+///
+/// ```
+/// use atoi::{from_radix_16_separated, Separator};
+/// let words = Separator::new('_').grouped(4);
+/// assert_eq!(
+///     (Some(0xdead_beefu32), 9),
+///     from_radix_16_separated(b"dead_beef", words)
+/// );
+/// ```
+pub fn from_radix_16_separated<I>(
+    text: impl AsRef<[u8]>,
+    separator: Separator,
+) -> (Option<I>, usize)
+where
+    I: FromRadix16Checked,
+{
+    I::from_radix_16_checked_separated(text.as_ref(), separator)
+}
+
+/// Parses a hexadecimal number with an optional leading sign from a slice, whose digits may be
+/// split by `separator`.
+///
+/// Acts like [`FromRadix16SignedChecked::from_radix_16_signed_checked_separated`].
+///
+/// # Example
+///
+/// ```
+/// use atoi::{from_radix_16_signed_separated, Separator};
+/// assert_eq!(
+///     (Some(-0x7f_ffi32), 6),
+///     from_radix_16_signed_separated(b"-7f_ff", Separator::new('_'))
+/// );
+/// ```
+pub fn from_radix_16_signed_separated<I>(
+    text: impl AsRef<[u8]>,
+    separator: Separator,
+) -> (Option<I>, usize)
+where
+    I: FromRadix16SignedChecked,
+{
+    I::from_radix_16_signed_checked_separated(text.as_ref(), separator)
+}
+
+/// Maximum number of significant digits passed on for a number with separators. A number with this
+/// many digits overflows any integer of up to 128 bits in any radix.
+const MAX_DIGITS: usize = 129;
+
+/// Parses a number, whose digits may be split by `separator`, using `parse` for the actual
+/// conversion. `parse` acts like one of the parsing traits with the given `radix`, and accepts a
+/// leading sign if `signed` is `true`.
+///
+/// Numbers without any separators are handed to `parse` as they are. Otherwise, their digits are
+/// copied into a buffer without the separators, so `parse` never sees a separator. Leading zeros
+/// are not copied. Significant digits beyond the first [`MAX_DIGITS`] are dropped. This does not
+/// change the outcome of checked or saturating parsing, since the number overflows either way.
+#[inline]
+pub(crate) fn parse_separated<T>(
+    text: &[u8],
+    radix: u32,
+    signed: bool,
+    separator: Separator,
+    parse: impl Fn(&[u8]) -> (T, usize),
+) -> (T, usize) {
+    let sign_len = match text.first().and_then(|&byte| Sign::try_from(byte)) {
+        Some(_) if signed => 1,
+        _ => 0,
+    };
+    let (number, used) = parse(text);
+    if separator
+        .skip(text, used, radix, used.saturating_sub(sign_len), true)
+        .is_none()
+    {
+        return (number, used);
+    }
+
+    let mut buffer = [0; 1 + MAX_DIGITS];
+    buffer[..sign_len].copy_from_slice(&text[..sign_len]);
+    let mut len = sign_len;
+    let mut index = sign_len;
+    // Number of digits since the last separator
+    let mut group_len = 0;
+    let mut first_group = true;
+    while index != text.len() {
+        let byte = text[index];
+        if (byte as char).is_digit(radix) {
+            if len != buffer.len() && (byte != b'0' || len != sign_len) {
+                buffer[len] = byte;
+                len += 1;
+            }
+            index += 1;
+            group_len += 1;
+        } else if let Some(next) = separator.skip(text, index, radix, group_len, first_group) {
+            index = next;
+            group_len = 0;
+            first_group = false;
+        } else {
+            break;
+        }
+    }
+    if len == sign_len {
+        // All digits have been zeros
+        buffer[len] = b'0';
+        len += 1;
+    }
+    let (number, _) = parse(&buffer[..len]);
+    (number, index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Integer;
+
+    #[test]
+    fn separated_parsing() {
+        let underscore = Separator::new('_');
+        assert_eq!(
+            (Some(0), 0),
+            from_radix_10_separated::<u32>(b"_1", underscore)
+        );
+        assert_eq!(
+            (Some(1), 1),
+            from_radix_10_separated::<u32>(b"1__0", underscore)
+        );
+        assert_eq!(
+            (Some(10), 3),
+            from_radix_10_separated::<u32>(b"1_0_", underscore)
+        );
+        assert_eq!(
+            (Some(u64::MAX), 26),
+            from_radix_10_separated::<u64>(b"18_446_744_073_709_551_615", underscore)
+        );
+        assert_eq!(
+            (None, 26),
+            from_radix_10_separated::<u64>(b"18_446_744_073_709_551_616", underscore)
+        );
+        assert_eq!(
+            (Some(i8::MIN), 5),
+            from_radix_10_signed_separated::<i8>(b"-12_8", underscore)
+        );
+        assert_eq!(
+            (None, 5),
+            from_radix_10_signed_separated::<u8>(b"-12_8", underscore)
+        );
+
+        let thousands = Separator::new(',').grouped(3);
+        assert_eq!(
+            (Some(1), 1),
+            from_radix_10_separated::<u32>(b"1,2345", thousands)
+        );
+        assert_eq!(
+            (Some(1234), 4),
+            from_radix_10_separated::<u32>(b"1234,567", thousands)
+        );
+        assert_eq!(
+            (Some(123), 3),
+            from_radix_10_separated::<u32>(b"123,", thousands)
+        );
+        assert_eq!(
+            (Some(123_456), 7),
+            from_radix_10_separated::<u32>(b"123,456,78", thousands)
+        );
+        assert_eq!(
+            (Some(-0x1000), 6),
+            from_radix_16_signed_separated::<i32>(b"-1,000,0", thousands)
+        );
+
+        // Leading zeros do not count towards the digits of a number
+        assert_eq!(
+            (Some(i8::MIN), 6),
+            i8::from_radix_10_signed_checked_separated(b"-0_128", underscore)
+        );
+        let mut zeros = [b'0'; 301];
+        zeros[1..].chunks_mut(3).for_each(|group| group[0] = b'_');
+        zeros[300] = b'1';
+        assert_eq!(
+            (Some(1), 301),
+            u8::from_radix_10_checked_separated(&zeros, underscore)
+        );
+        let mut nines = [b'9'; 259];
+        nines[1..].chunks_mut(2).for_each(|group| group[0] = b'_');
+        assert_eq!(
+            (None, 259),
+            u128::from_radix_10_checked_separated(&nines, underscore)
+        );
+        assert_eq!(
+            (None, 5),
+            core::num::NonZeroU16::from_radix_16_checked_separated(b"0_000", underscore)
+        );
+        let (number, used) =
+            Integer::<i32>::from_radix_16_signed_checked_separated(b"-1,000", thousands);
+        assert_eq!((Some(-0x1000), 6), (number.map(|n| n.0), used));
+    }
+}
@@ -0,0 +1,252 @@
+//! Deserializing integers encoded as strings or byte strings of ASCII digits, e.g. the JSON
+//! string `"12345"`. Requires the `serde` feature.
+//!
+//! Use the [`AsciiInt`] wrapper type, or the [`deserialize`] function together with
+//! `#[serde(deserialize_with = "atoi::serde::deserialize")]`. The text must consist of a decimal
+//! number with an optional sign and nothing else, see [`crate::parse_exact`]. It is parsed in
+//! place, so no allocation takes place, regardless of whether the deserializer is able to lend
+//! out its input.
+//!
+//! The deserializer is asked for a byte string, so formats which are not self-describing, like
+//! bincode, work as well. Self-describing formats hand over whatever they find instead. Strings,
+//! byte strings and sequences of bytes are accepted, e.g. the JSON values `"12"` and `[49,50]`.
+//!
+//! ```
+//! use atoi::serde::AsciiInt;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Deserialize, Serialize)]
+//! struct Order {
+//!     id: AsciiInt<u64>,
+//!     #[serde(with = "atoi::serde")]
+//!     quantity: i32,
+//! }
+//!
+//! let order: Order = serde_json::from_str(r#"{"id":"12345","quantity":"-7"}"#).unwrap();
+//! assert_eq!(AsciiInt(12345), order.id);
+//! assert_eq!(-7, order.quantity);
+//! assert_eq!(
+//!     r#"{"id":"12345","quantity":"-7"}"#,
+//!     serde_json::to_string(&order).unwrap()
+//! );
+//!
+//! let error = serde_json::from_str::<Order>(r#"{"id":"12x","quantity":"1"}"#).unwrap_err();
+//! assert_eq!(
+//!     "invalid value: string \"12x\", expected a decimal integer (invalid digit found at \
+//!     position 2) at line 1 column 11",
+//!     error.to_string()
+//! );
+//! ```
+
+use core::{
+    fmt::{self, Formatter},
+    marker::PhantomData,
+    str,
+};
+
+use ::serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, Expected, SeqAccess, Unexpected, Visitor},
+};
+
+use crate::{
+    DecimalAccumulator, FromRadix10SignedChecked, ParseIntegerError, ToRadix10, parse_exact,
+};
+
+/// An integer which is serialized as a string of ASCII digits. Deserializes from strings, byte
+/// strings and sequences of bytes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsciiInt<I>(pub I);
+
+impl<'de, I> Deserialize<'de> for AsciiInt<I>
+where
+    I: FromRadix10SignedChecked,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize(deserializer).map(AsciiInt)
+    }
+}
+
+impl<I> Serialize for AsciiInt<I>
+where
+    I: ToRadix10 + Copy,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize(&self.0, serializer)
+    }
+}
+
+/// Deserializes an integer from a string, a byte string or a sequence of bytes of ASCII digits.
+/// Intended for use with `#[serde(deserialize_with = "atoi::serde::deserialize")]`.
+pub fn deserialize<'de, D, I>(deserializer: D) -> Result<I, D::Error>
+where
+    D: Deserializer<'de>,
+    I: FromRadix10SignedChecked,
+{
+    deserializer.deserialize_bytes(AsciiIntVisitor(PhantomData))
+}
+
+/// Serializes an integer as a string of ASCII digits. Intended for use with
+/// `#[serde(serialize_with = "atoi::serde::serialize")]`.
+pub fn serialize<S, I>(value: &I, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    I: ToRadix10 + Copy,
+{
+    // Enough to hold a sign and the digits of any builtin integer.
+    let mut buffer = [0; 40];
+    let len = value.to_radix_10(&mut buffer);
+    let text = str::from_utf8(&buffer[..len]).expect("Digits and signs must be ASCII");
+    serializer.serialize_str(text)
+}
+
+struct AsciiIntVisitor<I>(PhantomData<I>);
+
+impl<'de, I> Visitor<'de> for AsciiIntVisitor<I>
+where
+    I: FromRadix10SignedChecked,
+{
+    type Value = I;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a decimal integer as string, byte string or sequence of bytes"
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<I, E>
+    where
+        E: de::Error,
+    {
+        self.visit_bytes(v.as_bytes())
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<I, E>
+    where
+        E: de::Error,
+    {
+        parse_exact(v).map_err(|error| {
+            // Show the offending text, if it is printable
+            let unexpected = match str::from_utf8(v) {
+                Ok(text) => Unexpected::Str(text),
+                Err(_) => Unexpected::Bytes(v),
+            };
+            E::invalid_value(unexpected, &InvalidInteger(error))
+        })
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<I, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut accumulator = DecimalAccumulator::signed();
+        let mut position = 0;
+        while let Some(byte) = seq.next_element::<u8>()? {
+            // Overflows are reported by `finish`, once all bytes have been seen.
+            if let Ok(Some(_)) = accumulator.feed(&[byte]) {
+                let error = ParseIntegerError::InvalidDigit { position };
+                return Err(de::Error::invalid_value(
+                    Unexpected::Seq,
+                    &InvalidInteger(error),
+                ));
+            }
+            position += 1;
+        }
+        accumulator
+            .finish()
+            .map_err(|error| de::Error::invalid_value(Unexpected::Seq, &InvalidInteger(error)))
+    }
+}
+
+/// Explains why a string could not be deserialized as an integer.
+struct InvalidInteger(ParseIntegerError);
+
+impl Expected for InvalidInteger {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "a decimal integer ({})", self.0)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use ::serde::de::value::{BytesDeserializer, Error};
+
+    use super::*;
+
+    #[test]
+    fn deserialize_ascii_integers() {
+        #[derive(Deserialize)]
+        struct Message<'a> {
+            #[serde(deserialize_with = "crate::serde::deserialize")]
+            id: u32,
+            name: &'a str,
+        }
+
+        let message: Message = serde_json::from_str(r#"{"id":"42","name":"x"}"#).unwrap();
+        assert_eq!((42, "x"), (message.id, message.name));
+        // Escaped strings can not be borrowed, but are accepted anyway
+        assert_eq!(
+            AsciiInt(-1),
+            serde_json::from_str::<AsciiInt<i8>>(r#""-1""#).unwrap()
+        );
+        assert_eq!(
+            "invalid value: string \"256\", expected a decimal integer (number too large to fit \
+            in target type) at line 1 column 5",
+            serde_json::from_str::<AsciiInt<u8>>(r#""256""#)
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "invalid type: integer `42`, expected a decimal integer as string, byte string or \
+            sequence of bytes at line 1 column 2",
+            serde_json::from_str::<AsciiInt<u8>>("42")
+                .unwrap_err()
+                .to_string()
+        );
+
+        // JSON has no byte strings, but sequences of bytes are accepted as well
+        assert_eq!(
+            AsciiInt(-12),
+            serde_json::from_str::<AsciiInt<i8>>("[45, 49, 50]").unwrap()
+        );
+        assert_eq!(
+            "invalid value: sequence, expected a decimal integer (invalid digit found at \
+            position 1) at line 1 column 8",
+            serde_json::from_str::<AsciiInt<u8>>("[49,120]")
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "invalid value: sequence, expected a decimal integer (number too large to fit in \
+            target type) at line 1 column 10",
+            serde_json::from_str::<AsciiInt<u8>>("[50,53,54]")
+                .unwrap_err()
+                .to_string()
+        );
+
+        let deserializer = BytesDeserializer::<Error>::new(b"-128");
+        assert_eq!(
+            AsciiInt(-128i8),
+            AsciiInt::deserialize(deserializer).unwrap()
+        );
+        let deserializer = BytesDeserializer::<Error>::new(b"\xff1");
+        assert_eq!(
+            "invalid value: byte array, expected a decimal integer (invalid digit found at \
+            position 0)",
+            AsciiInt::<u8>::deserialize(deserializer)
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "\"-170141183460469231731687303715884105728\"",
+            serde_json::to_string(&AsciiInt(i128::MIN)).unwrap()
+        );
+    }
+}
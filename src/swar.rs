@@ -0,0 +1,151 @@
+//! SWAR (SIMD within a register) routines, which process eight ASCII digits at once using `u64`
+//! arithmetic.
+
+/// Parses the first eight bytes of `text`, if `text` is at least eight bytes long and all of them
+/// are ASCII digits.
+#[inline]
+pub fn eight_digits(text: &[u8]) -> Option<u32> {
+    let chunk = u64::from_le_bytes(text.get(..8)?.try_into().unwrap());
+    if is_eight_digits(chunk) {
+        Some(parse_eight_digits(chunk))
+    } else {
+        None
+    }
+}
+
+/// Parses a fixed width field of ASCII digits, which may be padded with leading spaces. The field is
+/// processed in chunks of eight bytes without branching on individual bytes. A first chunk shorter
+/// than eight bytes is treated as if it was padded with spaces.
+///
+/// `None` if the field contains any other byte, consists only of padding, or the number does not
+/// fit into `u128`.
+#[inline]
+pub fn fixed_width_digits<const N: usize>(field: &[u8; N]) -> Option<u128> {
+    let num_missing = (8 - N % 8) % 8;
+    let mut number: u128 = 0;
+    // Multiplying `u64` is cheaper, so we use it for narrow fields.
+    let mut number_u64: u64 = 0;
+    let mut overflow = false;
+    let mut valid = true;
+    // `true` as long as no byte other than padding has been seen.
+    let mut padding = true;
+    for index in 0..N.div_ceil(8) {
+        let begin = (8 * index).saturating_sub(num_missing);
+        let end = 8 * (index + 1) - num_missing;
+        let mut bytes = [b' '; 8];
+        bytes[8 - (end - begin)..].copy_from_slice(&field[begin..end]);
+        let chunk = u64::from_le_bytes(bytes);
+
+        // Replace the leading spaces with b'0' (0x30), by adding 0x10 to each of them.
+        let not_space = non_zero_bytes(chunk ^ 0x2020_2020_2020_2020);
+        // Isolating the lowest set bit marks the first byte, which is not a space. Subtracting one
+        // sets all bits of the bytes in front of it. All bits are set if there is no such byte.
+        let first_not_space = (not_space & not_space.wrapping_neg()) >> 7;
+        let padding_mask = first_not_space.wrapping_sub(1) & (padding as u64).wrapping_neg();
+        let chunk = chunk + (padding_mask & 0x1010_1010_1010_1010);
+        padding &= not_space == 0;
+        valid &= is_eight_digits(chunk);
+
+        // Garbage for invalid chunks, yet discarded in the end. Checking `valid` only once keeps the
+        // loop free of branches.
+        let digits = parse_eight_digits(chunk);
+        // Resolved at compile time. 16 digits always fit into `u64`, 38 digits into `u128`.
+        if N <= 16 {
+            number_u64 = number_u64
+                .wrapping_mul(100_000_000)
+                .wrapping_add(u64::from(digits));
+        } else if N <= 38 {
+            number = number
+                .wrapping_mul(100_000_000)
+                .wrapping_add(u128::from(digits));
+        } else {
+            let (product, mul_overflow) = number.overflowing_mul(100_000_000);
+            let (sum, add_overflow) = product.overflowing_add(u128::from(digits));
+            overflow |= mul_overflow | add_overflow;
+            number = sum;
+        }
+    }
+    if N <= 16 {
+        number = u128::from(number_u64);
+    }
+    (valid && !padding && !overflow).then_some(number)
+}
+
+/// Sets the most significant bit of every non zero byte in `chunk`, and clears all other bits.
+#[inline]
+fn non_zero_bytes(chunk: u64) -> u64 {
+    // Adding 0x7F to the lower seven bits sets the most significant bit, unless they are all zero.
+    // This can not carry into the next byte.
+    let low = (chunk & 0x7F7F_7F7F_7F7F_7F7F) + 0x7F7F_7F7F_7F7F_7F7F;
+    (low | chunk) & 0x8080_8080_8080_8080
+}
+
+/// `true` if all eight bytes of `chunk` are ASCII digits.
+#[inline]
+fn is_eight_digits(chunk: u64) -> bool {
+    // The upper nibble of every byte must be `3`. Adding `6` to a digit must not change the upper
+    // nibble, which is the case for b'0' (0x30) to b'9' (0x39), but not for b':' (0x3A) to 0x3F.
+    let upper = chunk & 0xF0F0_F0F0_F0F0_F0F0;
+    let upper_plus_six = chunk.wrapping_add(0x0606_0606_0606_0606) & 0xF0F0_F0F0_F0F0_F0F0;
+    (upper | (upper_plus_six >> 4)) == 0x3333_3333_3333_3333
+}
+
+/// Interprets the eight ASCII digits in `chunk` as decimal number. The first digit is the least
+/// significant byte of `chunk`.
+#[inline]
+fn parse_eight_digits(chunk: u64) -> u32 {
+    let digits = chunk.wrapping_sub(0x3030_3030_3030_3030);
+    // Combine adjacent digits into numbers from 0 to 99 stored in every other byte.
+    let pairs = digits.wrapping_mul(10).wrapping_add(digits >> 8);
+    // Combine the four pairs to one number using two multiplications, which place their results
+    // in the upper half.
+    let lower = (pairs & 0x0000_00FF_0000_00FF).wrapping_mul(100 + (1_000_000 << 32));
+    let upper = ((pairs >> 16) & 0x0000_00FF_0000_00FF).wrapping_mul(1 + (10_000 << 32));
+    (lower.wrapping_add(upper) >> 32) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FromRadix10, FromRadix10Checked, FromRadix10Signed, FromRadix10SignedChecked};
+
+    #[test]
+    fn long_numbers_with_non_digit_at_any_position() {
+        let digits = *b"12345678901234567890123456789012345678";
+        for position in 0..digits.len() {
+            for non_digit in [b'/', b':', b' ', b'a', 0x00, 0x7f, 0xb0, 0xff] {
+                let mut text = digits;
+                text[position] = non_digit;
+                let expected = digits[..position]
+                    .iter()
+                    .fold(0u128, |n, &digit| n * 10 + u128::from(digit - b'0'));
+
+                assert_eq!((expected, position), u128::from_radix_10(&text));
+                assert_eq!(
+                    (Some(expected), position),
+                    u128::from_radix_10_checked(&text)
+                );
+                if position <= 19 {
+                    let expected = expected as u64;
+                    assert_eq!((expected, position), u64::from_radix_10(&text[..19]));
+                    assert_eq!(
+                        (Some(expected), position),
+                        u64::from_radix_10_checked(&text)
+                    );
+                }
+                if position <= 18 {
+                    let mut negative = [b'-'; 39];
+                    negative[1..].copy_from_slice(&text);
+                    let expected = -(expected as i64);
+                    assert_eq!(
+                        (Some(expected), position + 1),
+                        i64::from_radix_10_signed_checked(&negative)
+                    );
+                    assert_eq!(
+                        (expected, position + 1),
+                        i64::from_radix_10_signed(&negative[..19])
+                    );
+                }
+            }
+        }
+    }
+}
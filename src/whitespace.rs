@@ -0,0 +1,176 @@
+//! Skipping whitespace around numbers, like C's `strtol` does.
+
+use crate::{FromRadix10SignedChecked, ParseIntegerError};
+
+/// Whether and where whitespace is allowed around a number. Whitespace is any byte recognized by
+/// C's `isspace`, i.e. space, `\t`, `\n`, `\v` (vertical tab), `\x0C` (form feed) and `\r`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Whitespace {
+    /// Do not skip whitespace in front of the number, it is rejected like any other non digit.
+    /// Trailing bytes are ignored. Like [`crate::atoi`].
+    NoSkip,
+    /// Skip whitespace in front of the number. Trailing bytes are ignored. Like C's `atoi` and
+    /// `strtol`.
+    SkipLeading,
+    /// Skip whitespace in front of the number. Only whitespace may follow the number.
+    Trim,
+}
+
+/// Parses an integer from a slice, optionally surrounded by whitespace.
+///
+/// # Example
+///
+/// ```
+/// use atoi::{atoi, atoi_with_whitespace, Whitespace};
+/// assert_eq!(None, atoi::<u32>(b"  42"));
+/// assert_eq!(Some(42), atoi_with_whitespace::<u32>(b"  42", Whitespace::SkipLeading));
+/// assert_eq!(Some(-42), atoi_with_whitespace::<i32>(b"\t-42 apples", Whitespace::SkipLeading));
+/// assert_eq!(None, atoi_with_whitespace::<i32>(b"\t-42 apples", Whitespace::Trim));
+/// assert_eq!(Some(-42), atoi_with_whitespace::<i32>(b"\t-42\r\n", Whitespace::Trim));
+/// ```
+///
+/// # Return
+///
+/// Returns a number if the slice started with a number after skipping whitespace, and no other
+/// bytes than the ones allowed by `whitespace` follow it. Otherwise `None` is returned.
+#[inline]
+pub fn atoi_with_whitespace<I>(text: impl AsRef<[u8]>, whitespace: Whitespace) -> Option<I>
+where
+    I: FromRadix10SignedChecked,
+{
+    try_atoi_with_whitespace(text, whitespace)
+        .ok()
+        .map(|(n, _)| n)
+}
+
+/// Parses an integer from a slice, optionally surrounded by whitespace, and reports why parsing
+/// failed.
+///
+/// Acts like [`atoi_with_whitespace`], but in addition to the integer it returns the index of the
+/// byte right after the parsed number, counting any leading whitespace. Like the end pointer of
+/// `strtol`, this index does not include trailing whitespace.
+///
+/// # Example
+///
+/// ```
+/// use atoi::{try_atoi_with_whitespace, ParseIntegerError, Whitespace};
+/// assert_eq!(Ok((42, 4)), try_atoi_with_whitespace::<u32>(b"  42 ", Whitespace::Trim));
+/// // `\v` counts as whitespace
+/// assert_eq!(Ok((7, 2)), try_atoi_with_whitespace::<u32>(b"\x0b7", Whitespace::SkipLeading));
+/// assert_eq!(
+///     Err(ParseIntegerError::InvalidDigit { position: 5 }),
+///     try_atoi_with_whitespace::<u32>(b"  42 x", Whitespace::Trim)
+/// );
+/// // Positions within the number count leading whitespace as well
+/// assert_eq!(
+///     Err(ParseIntegerError::InvalidDigit { position: 2 }),
+///     try_atoi_with_whitespace::<i32>(b" -x", Whitespace::SkipLeading)
+/// );
+/// assert_eq!(
+///     Err(ParseIntegerError::Empty),
+///     try_atoi_with_whitespace::<u32>(b" \n", Whitespace::Trim)
+/// );
+/// ```
+#[inline]
+pub fn try_atoi_with_whitespace<I>(
+    text: impl AsRef<[u8]>,
+    whitespace: Whitespace,
+) -> Result<(I, usize), ParseIntegerError>
+where
+    I: FromRadix10SignedChecked,
+{
+    let text = text.as_ref();
+    let start = match whitespace {
+        Whitespace::NoSkip => 0,
+        Whitespace::SkipLeading | Whitespace::Trim => leading_whitespace(text),
+    };
+    let (number, used) = I::try_from_radix_10_signed_checked(&text[start..])
+        .map_err(|error| error.shifted(start))?;
+    let end = start + used;
+    if whitespace == Whitespace::Trim {
+        check_trailing_whitespace(text, end)?;
+    }
+    Ok((number, end))
+}
+
+/// `true` for the bytes C's `isspace` recognizes as whitespace. Contrary to
+/// [`u8::is_ascii_whitespace`] this includes the vertical tab.
+#[inline]
+pub(crate) fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\n' | b'\x0B' | b'\x0C' | b'\r')
+}
+
+/// Number of whitespace bytes at the start of `text`.
+#[inline]
+pub(crate) fn leading_whitespace(text: &[u8]) -> usize {
+    text.iter().take_while(|&&byte| is_whitespace(byte)).count()
+}
+
+/// Reports the first byte other than whitespace at or after `end` as an invalid digit.
+#[inline]
+pub(crate) fn check_trailing_whitespace(text: &[u8], end: usize) -> Result<(), ParseIntegerError> {
+    match text[end..].iter().position(|&byte| !is_whitespace(byte)) {
+        Some(offset) => Err(ParseIntegerError::InvalidDigit {
+            position: end + offset,
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ParseOptions, TrailingPolicy, atoi, parse};
+
+    #[test]
+    fn skip_whitespace_like_strtol() {
+        let all = b" \t\n\x0b\x0c\r";
+        assert_eq!(None, atoi::<u8>(b" 1"));
+        assert_eq!(
+            Ok((1, 7)),
+            try_atoi_with_whitespace::<u8>(b" \t\n\x0b\x0c\r1", Whitespace::SkipLeading)
+        );
+        assert_eq!(
+            Ok((1, 1)),
+            try_atoi_with_whitespace::<u8>(b"1 \t\n\x0b\x0c\r", Whitespace::Trim)
+        );
+        assert_eq!(
+            Err(ParseIntegerError::InvalidDigit { position: 0 }),
+            try_atoi_with_whitespace::<u8>(b" 1", Whitespace::NoSkip)
+        );
+        assert_eq!(
+            Ok((1, 1)),
+            try_atoi_with_whitespace::<u8>(b"1 x", Whitespace::NoSkip)
+        );
+        assert_eq!(
+            Err(ParseIntegerError::SignOnly),
+            try_atoi_with_whitespace::<i8>(b"\t-", Whitespace::Trim)
+        );
+        assert_eq!(
+            Err(ParseIntegerError::InvalidDigit { position: 3 }),
+            try_atoi_with_whitespace::<i8>(b"\t1\x0b\x00", Whitespace::Trim)
+        );
+        assert_eq!(
+            Err(ParseIntegerError::NegOverflow),
+            try_atoi_with_whitespace::<i8>(b" -129 ", Whitespace::Trim)
+        );
+        assert_eq!(
+            Err(ParseIntegerError::Empty),
+            try_atoi_with_whitespace::<i8>(&all[..], Whitespace::SkipLeading)
+        );
+        assert_eq!(
+            Some(-5),
+            atoi_with_whitespace::<i8>("\r\n-5\r\n", Whitespace::Trim)
+        );
+
+        // The same rules apply to `parse`
+        let options = ParseOptions::new()
+            .skip_leading_whitespace(true)
+            .trailing(TrailingPolicy::Whitespace);
+        assert_eq!(Ok((-5, 3)), parse::<i8>(&options, b"\x0b-5\x0b"));
+        assert_eq!(
+            Err(ParseIntegerError::InvalidDigit { position: 4 }),
+            parse::<i8>(&options, b"\x0b-5 ,")
+        );
+    }
+}
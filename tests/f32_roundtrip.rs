@@ -0,0 +1,44 @@
+use atoi::FromRadix10Float;
+use proptest::prelude::{any, proptest};
+
+type N = f32;
+
+proptest! {
+    #[test]
+    fn roundtrip_display(n in any::<N>()) {
+        let text = n.to_string();
+        let (actual, len) = N::from_radix_10_float(text.as_bytes());
+
+        assert_eq!(text.len(), len);
+        if n.is_nan() {
+            assert!(actual.is_nan());
+        } else {
+            assert_eq!(n, actual);
+        }
+    }
+
+    #[test]
+    fn roundtrip_exponent(n in any::<N>()) {
+        let text = format!("{n:e}");
+        let (actual, len) = N::from_radix_10_float(text.as_bytes());
+
+        assert_eq!(text.len(), len);
+        if n.is_nan() {
+            assert!(actual.is_nan());
+        } else {
+            assert_eq!(n, actual);
+        }
+    }
+
+    #[test]
+    fn same_as_std(text in "[+-]?[0-9]{0,25}(\\.[0-9]{0,25})?([eE][+-]?[0-9]{0,4})?[a-z ]?") {
+        let (actual, len) = N::from_radix_10_float(text.as_bytes());
+
+        // The longest prefix, which the standard library accepts as number
+        let expected = (0..=text.len())
+            .rev()
+            .find_map(|len| text[..len].parse::<N>().ok().map(|n| (n, len)))
+            .unwrap_or((0., 0));
+        assert_eq!(expected, (actual, len));
+    }
+}
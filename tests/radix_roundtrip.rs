@@ -0,0 +1,136 @@
+//! Roundtrip tests, which are the same for every builtin integer type. Tests specific to a type are
+//! found in the roundtrip test of that type.
+
+macro_rules! radix_roundtrip {
+    ($($name:ident: $t:ty),* $(,)?) => {
+        $(
+            mod $name {
+                use atoi::{
+                    FromConstRadixChecked, FromConstRadixSignedChecked, FromRadix,
+                    FromRadix10SignedChecked, FromRadix16Signed, FromRadix16SignedChecked,
+                    FromRadixChecked, FromRadixSignedChecked, Integer, MaxNumDigits, ToRadix10,
+                    ToRadix16,
+                };
+                use proptest::prelude::*;
+
+                type N = $t;
+
+                proptest! {
+                    #[test]
+                    fn roundtrip_radix_2(n in 0..=N::MAX) {
+                        let text = format!("{n:b}");
+                        let (actual, len) = N::from_radix(text.as_bytes(), 2);
+
+                        assert_eq!(text.len(), len);
+                        assert_eq!(n, actual);
+                    }
+
+                    #[test]
+                    fn roundtrip_radix_8_checked(n in 0..=N::MAX) {
+                        let text = format!("{n:o}");
+                        let (actual, len) = N::from_radix_checked(text.as_bytes(), 8);
+
+                        assert_eq!(text.len(), len);
+                        assert_eq!(Some(n), actual);
+                    }
+
+                    #[test]
+                    fn roundtrip_radix_signed_checked(n in N::MIN..=N::MAX) {
+                        let text = n.to_string();
+                        let (actual, len) = N::from_radix_signed_checked(text.as_bytes(), 10);
+
+                        assert_eq!(text.len(), len);
+                        assert_eq!(Some(n), actual);
+                    }
+
+                    #[test]
+                    fn roundtrip_const_radix_2_checked(n in 0..=N::MAX) {
+                        let text = format!("{n:b}");
+                        let (actual, len) = N::from_const_radix_checked::<2>(text.as_bytes());
+
+                        assert_eq!(text.len(), len);
+                        assert_eq!(Some(n), actual);
+                    }
+
+                    #[test]
+                    fn roundtrip_const_radix_signed_checked(n in N::MIN..=N::MAX) {
+                        let text = n.to_string();
+                        let (actual, len) =
+                            N::from_const_radix_signed_checked::<10>(text.as_bytes());
+
+                        assert_eq!(text.len(), len);
+                        assert_eq!(Some(n), actual);
+                    }
+
+                    #[test]
+                    fn roundtrip_radix_16_with_sign(n in N::MIN..=N::MAX, uppercase: bool) {
+                        let sign = if n < N::default() { "-" } else { "" };
+                        let text = if uppercase {
+                            format!("{sign}{:X}", n.abs_diff(0))
+                        } else {
+                            format!("{sign}{:x}", n.abs_diff(0))
+                        };
+                        let (actual, len) = N::from_radix_16_signed(text.as_bytes());
+
+                        assert_eq!(text.len(), len);
+                        assert_eq!(n, actual);
+                    }
+
+                    #[test]
+                    fn roundtrip_radix_16_with_sign_checked(
+                        n in N::MIN..=N::MAX,
+                        uppercase: bool,
+                    ) {
+                        let sign = if n < N::default() { "-" } else { "" };
+                        let text = if uppercase {
+                            format!("{sign}{:X}", n.abs_diff(0))
+                        } else {
+                            format!("{sign}{:x}", n.abs_diff(0))
+                        };
+                        let (actual, len) = N::from_radix_16_signed_checked(text.as_bytes());
+
+                        assert_eq!(text.len(), len);
+                        assert_eq!(Some(n), actual);
+                    }
+
+                    #[test]
+                    fn roundtrip_to_radix_10(n in N::MIN..=N::MAX) {
+                        let mut buffer = vec![0; N::max_num_bytes(10)];
+                        let len = n.to_radix_10(&mut buffer);
+
+                        assert_eq!(n.to_string().as_bytes(), &buffer[..len]);
+                        assert_eq!((Some(n), len), N::from_radix_10_signed_checked(&buffer[..len]));
+                        assert_eq!(len, Integer(n).to_radix_10(&mut buffer));
+                        assert_eq!(n.to_string().as_bytes(), &buffer[..len]);
+                    }
+
+                    #[test]
+                    fn roundtrip_to_radix_16(n in N::MIN..=N::MAX) {
+                        let mut buffer = vec![0; N::max_num_bytes(16)];
+                        let len = n.to_radix_16(&mut buffer);
+
+                        assert_eq!((Some(n), len), N::from_radix_16_signed_checked(&buffer[..len]));
+                        let mut generic = vec![0; N::max_num_bytes(16)];
+                        assert_eq!(len, Integer(n).to_radix_16(&mut generic));
+                        assert_eq!(&buffer[..len], &generic[..len]);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+radix_roundtrip! {
+    i8_roundtrip: i8,
+    u8_roundtrip: u8,
+    i16_roundtrip: i16,
+    u16_roundtrip: u16,
+    i32_roundtrip: i32,
+    u32_roundtrip: u32,
+    i64_roundtrip: i64,
+    u64_roundtrip: u64,
+    i128_roundtrip: i128,
+    u128_roundtrip: u128,
+    isize_roundtrip: isize,
+    usize_roundtrip: usize,
+}
@@ -1,6 +1,6 @@
 use atoi::{
     FromRadix10, FromRadix10Checked, FromRadix10Signed, FromRadix10SignedChecked, FromRadix16,
-    FromRadix16Checked,
+    FromRadix16Checked, from_fixed_width_radix_10,
 };
 use proptest::prelude::proptest;
 
@@ -68,4 +68,16 @@ proptest! {
         assert_eq!(text.len(), len);
         assert_eq!(Some(n), actual);
     }
+
+    #[test]
+    fn roundtrip_fixed_width(n in 0..=N::MAX, zeros: bool) {
+        let text = if zeros {
+            format!("{n:020}")
+        } else {
+            format!("{n:20}")
+        };
+        let field: &[u8; 20] = text.as_bytes().try_into().unwrap();
+
+        assert_eq!(Ok(n), from_fixed_width_radix_10::<N, 20>(field));
+    }
 }